@@ -60,4 +60,158 @@ pub enum InputError {
     InvalidReveal,
     #[msg("Max Dispute Votes Must be <= 200.")]
     MaxDisputeTooHigh,
-}
\ No newline at end of file
+    #[msg("Soulbound reputation cannot be staked, rep costs must be zero.")]
+    SoulboundReputationNotTransferable,
+    #[msg("The reputation mint of a soulbound court cannot be changed.")]
+    SoulboundMintLocked,
+    #[msg("Dispute winner never submitted a case.")]
+    NoWinningCase,
+    #[msg("Payout calculation overflowed.")]
+    PayoutOverflow,
+    #[msg("Staked amount would overflow or go below zero.")]
+    StakeOverflow,
+    #[msg("Vault did not receive the expected token amount.")]
+    TransferAmountMismatch,
+    #[msg("Mint is not owned by the provided token program.")]
+    TokenProgramMismatch,
+    #[msg("Dispute does not hold enough lamports above rent for this payout.")]
+    InsufficientVaultLamports,
+    #[msg("Party costs must be empty or have one entry per user.")]
+    PartyCostsLengthMismatch,
+    #[msg("Only concluded disputes can be appealed.")]
+    AppealRequiresConcludedDispute,
+    #[msg("Appeal bond must be non-zero and funding must end in the future.")]
+    InvalidAppealConfig,
+    #[msg("Appeals can only be crowdfunded in the court's pay mint.")]
+    AppealRequiresPayMint,
+    #[msg("Appeal funding period has ended.")]
+    AppealFundingClosed,
+    #[msg("Appeal funding period has not ended yet.")]
+    AppealFundingOpen,
+    #[msg("Appeal side does not exist.")]
+    AppealSideInvalid,
+    #[msg("Appeal side is already fully funded.")]
+    AppealSideFunded,
+    #[msg("No side was fully funded, appeal cannot be opened.")]
+    AppealNotFunded,
+    #[msg("Appeal has already been opened.")]
+    AppealAlreadyOpened,
+    #[msg("Appeal dispute does not match the appeal fund.")]
+    AppealDisputeMismatch,
+    #[msg("Appeal has not been resolved yet.")]
+    AppealNotResolved,
+    #[msg("Not enough free juror stake.")]
+    InsufficientFreeStake,
+    #[msg("No juror stake is unbonding.")]
+    NothingToWithdraw,
+    #[msg("Juror stake is still unbonding.")]
+    UnbondingNotFinished,
+    #[msg("Unbonding period cannot be negative.")]
+    InvalidUnbondingPeriod,
+    #[msg("Max dispute votes cannot go below the longest live claim queue.")]
+    MaxDisputeBelowLiveQueue,
+    #[msg("Record cannot shrink below its current claim queue.")]
+    RecordQueueTooLong,
+    #[msg("No disputes to claim.")]
+    ClaimListEmpty,
+    #[msg("Remaining accounts do not match the disputes being claimed.")]
+    ClaimAccountsMismatch,
+    #[msg("Token account is not the owner's associated token account.")]
+    OwnerAtaMismatch,
+    #[msg("Record still has disputes or stake, claim and withdraw first.")]
+    RecordNotSettled,
+    #[msg("No dispute config given and the protocol has no defaults.")]
+    ProtocolDefaultsMissing,
+    #[msg("Protocol has reached its dispute rate limit.")]
+    ProtocolRateLimited,
+    #[msg("Rate limit needs a positive window.")]
+    InvalidRateLimit,
+    #[msg("Pass either a dispute config or a template, not both.")]
+    TemplateConflict,
+    #[msg("Dispute template belongs to another court.")]
+    TemplateCourtMismatch,
+    #[msg("Court does not allow overriding this template field.")]
+    TemplateFieldLocked,
+    #[msg("Config bounds are inconsistent.")]
+    InvalidConfigBounds,
+    #[msg("Dispute has more parties than the court allows.")]
+    TooManyParties,
+    #[msg("A dispute phase is shorter than the court allows.")]
+    PhaseTooShort,
+    #[msg("A dispute phase is longer than the court allows.")]
+    PhaseTooLong,
+    #[msg("Dispute min_votes is below the court's quorum.")]
+    QuorumTooLow,
+    #[msg("Dispute voter_rep_required is above the court's maximum.")]
+    VoterRepRequiredTooHigh,
+    #[msg("Dispute voter_rep_cost is above the court's maximum.")]
+    VoterRepCostTooHigh,
+    #[msg("A party's rep cost is outside the court's range.")]
+    PartyRepCostOutOfBounds,
+    #[msg("A party's pay cost is outside the court's range.")]
+    PartyPayCostOutOfBounds,
+    #[msg("No transfer is pending for this signer.")]
+    NoPendingTransfer,
+    #[msg("Court change delay can't be negative.")]
+    InvalidChangeDelay,
+    #[msg("Court change is not effective yet.")]
+    CourtChangeNotEffective,
+    #[msg("Court is paused or winding down.")]
+    CourtNotActive,
+    #[msg("Court is winding down.")]
+    CourtWindingDown,
+    #[msg("Court is not winding down.")]
+    CourtNotWindingDown,
+    #[msg("Dispute has already concluded.")]
+    DisputeAlreadyConcluded,
+    #[msg("Court has no parent court.")]
+    CourtHasNoParent,
+    #[msg("Sub-court name must be the parent's name followed by '/'.")]
+    SubCourtNameInvalid,
+    #[msg("Soulbound courts can't have sub-courts.")]
+    SubCourtOfSoulbound,
+    #[msg("Child court record doesn't belong to a sub-court of this court.")]
+    ChildRecordMismatch,
+    #[msg("Court only accepts allow-listed jurors.")]
+    JurorNotAllowed,
+    #[msg("Juror doesn't hold an NFT from the court's collection.")]
+    NotInJurorCollection,
+    #[msg("Account is not the NFT's Metaplex metadata.")]
+    CollectionMetadataMismatch,
+    #[msg("Court metadata field is too long.")]
+    MetadataTooLong,
+    #[msg("Court policy version overflowed.")]
+    PolicyVersionOverflow,
+    #[msg("Evidence URI or MIME type is too long.")]
+    EvidenceTooLong,
+    #[msg("Court does not accept amicus evidence.")]
+    AmicusDisabled,
+    #[msg("Amicus spam threshold must be between 1 and 10000 bps.")]
+    InvalidAmicusConfig,
+    #[msg("Dispute parties submit evidence through their case.")]
+    AmicusIsParty,
+    #[msg("Only jurors that revealed their vote can flag amicus evidence.")]
+    AmicusFlagNotJuror,
+    #[msg("Amicus evidence can no longer be flagged.")]
+    AmicusFlaggingClosed,
+    #[msg("Appeal was not opened in time, contributions are being refunded.")]
+    AppealOpeningClosed,
+    #[msg("Appeal must run in a court with the same pay mint.")]
+    AppealMintMismatch,
+    #[msg("Juror stake vault must be empty before the reputation mint can change.")]
+    StakeVaultNotEmpty,
+    #[msg("Amicus bond must cover the rent exempt minimum of an empty account.")]
+    AmicusBondBelowRent,
+    #[msg("A user can only hold one slot in a dispute")]
+    DuplicateUser,
+    #[msg("Every live voter record of the court must be passed once, paired with its owner")]
+    LiveRecordsMismatch,
+    #[msg("Only a dispute concluded without a winner refunds the protocol, and only once.")]
+    ProtocolRefundUnavailable,
+    #[msg("The dispute template does not allow per-party costs.")]
+    TemplatePartyCostsLocked,
+    #[msg("The dispute template requires every user to be named.")]
+    TemplateOpenSlotsLocked,
+    #[msg("Flag accounts must come as [flag, juror] pairs of this submission.")]
+    AmicusFlagMismatch,
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct RecordClosed {
+    pub court: Pubkey,
+    pub owner: Pubkey,
+    pub lamports: u64, // rent returned to the owner
+}
+
+#[event]
+pub struct CourtChangeQueued {
+    pub court: Pubkey,
+    pub max_dispute_votes: u16,
+    pub unbonding_period: i64,
+    pub crank_tip: u64,
+    pub change_delay: i64,
+    pub rep_mint: Pubkey,
+    pub pay_mint: Option<Pubkey>,
+    pub effective_at: i64, // apply_court_change can run from here on
+}
+
+#[event]
+pub struct CourtChangeApplied {
+    pub court: Pubkey,
+}
+
+#[event]
+pub struct CourtChangeCancelled {
+    pub court: Pubkey,
+}
+
+#[event]
+pub struct CourtPolicyUpdated {
+    pub court: Pubkey,
+    pub policy_uri: String,
+    pub policy_hash: [u8; 32],
+    pub policy_version: u32, // new disputes record this version
+}
@@ -0,0 +1,28 @@
+use crate::{error::InputError, state::*};
+use anchor_lang::prelude::*;
+
+//proposed edit authority takes over the court, see propose_transfer
+
+pub fn accept_authority(ctx: Context<AcceptAuthority>, _court_name: String) -> Result<()> {
+    let court = &mut ctx.accounts.court;
+    court.edit_authority = ctx.accounts.new_authority.key();
+    court.pending_authority = None;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(
+        constraint = court.pending_authority == Some(new_authority.key()) @ InputError::NoPendingTransfer
+    )]
+    pub new_authority: Signer<'info>,
+}
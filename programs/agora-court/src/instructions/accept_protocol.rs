@@ -0,0 +1,29 @@
+use crate::{error::InputError, state::*};
+use anchor_lang::prelude::*;
+
+//proposed protocol takes over initialize_dispute for the court, see propose_transfer
+//disputes opened by the old protocol keep it as their protocol
+
+pub fn accept_protocol(ctx: Context<AcceptProtocol>, _court_name: String) -> Result<()> {
+    let court = &mut ctx.accounts.court;
+    court.protocol = ctx.accounts.new_protocol.key();
+    court.pending_protocol = None;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct AcceptProtocol<'info> {
+    #[account(
+        mut,
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(
+        constraint = court.pending_protocol == Some(new_protocol.key()) @ InputError::NoPendingTransfer
+    )]
+    pub new_protocol: Signer<'info>,
+}
@@ -0,0 +1,52 @@
+use crate::{error::InputError, state::*};
+use anchor_lang::prelude::*;
+
+//appends an item to the payer's own case, until init_cases_ends_at or voting starts
+//existing items are never changed - corrections go in as new items
+
+pub fn add_evidence(
+    ctx: Context<AddEvidence>,
+    _court_name: String,
+    _dispute_id: u64,
+    item: EvidenceInput
+) -> Result<()> {
+    item.validate()?;
+
+    ctx.accounts.dispute.can_add_evidence()?;
+
+    ctx.accounts.case.push(item, ctx.accounts.payer.key());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String, _dispute_id: u64, item: EvidenceInput)]
+pub struct AddEvidence<'info> {
+    #[account(
+        mut,
+        seeds = ["case".as_bytes(), dispute.key().as_ref(), payer.key().as_ref()],
+        bump = case.bump,
+        realloc = case.size_with(&item),
+        realloc::payer = payer,
+        realloc::zero = false,
+    )]
+    pub case: Account<'info, Case>,
+
+    #[account(
+        seeds = ["dispute".as_bytes(), court.key().as_ref(), _dispute_id.to_be_bytes().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+        constraint = court.status == CourtStatus::Active @ InputError::CourtNotActive
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>, // case owner
+
+    pub system_program: Program<'info, System>,
+}
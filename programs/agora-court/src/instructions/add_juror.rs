@@ -0,0 +1,45 @@
+use crate::{error::InputError, state::*};
+use anchor_lang::prelude::*;
+
+//admits a juror to the court's allow-list, see set_juror_allowlist
+
+pub fn add_juror(ctx: Context<AddJuror>, _court_name: String) -> Result<()> {
+    let pass = &mut ctx.accounts.juror_pass;
+    let bump = *ctx.bumps.get("juror_pass").unwrap();
+    pass.set_inner(JurorPass {
+        juror: ctx.accounts.juror.key(),
+        bump,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct AddJuror<'info> {
+    #[account(
+        init,
+        seeds = ["juror".as_bytes(), court.key().as_ref(), juror.key().as_ref()],
+        bump,
+        payer = authority,
+        space = JurorPass::SIZE
+    )]
+    pub juror_pass: Account<'info, JurorPass>,
+
+    #[account(
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(
+        mut,
+        constraint = court.edit_authority == authority.key() @ InputError::InvalidEditAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    ///CHECK: juror being admitted, only used as a seed
+    pub juror: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,59 @@
+use crate::{error::InputError, state::*};
+use anchor_lang::prelude::*;
+
+//allow-lists another protocol to open disputes in the court, next to court.protocol
+//to change an entry's defaults / rate limit, remove and add it again
+
+pub fn add_protocol(
+    ctx: Context<AddProtocol>,
+    _court_name: String,
+    defaults: Option<DisputeDefaults>,
+    rate_limit: Option<RateLimit>
+) -> Result<()> {
+    if let Some(limit) = &rate_limit {
+        require!(limit.window > 0, InputError::InvalidRateLimit);
+    }
+
+    let entry = &mut ctx.accounts.protocol_entry;
+    let bump = *ctx.bumps.get("protocol_entry").unwrap();
+    entry.set_inner(ProtocolEntry {
+        protocol: ctx.accounts.protocol.key(),
+        defaults,
+        rate_limit,
+        window_started_at: Clock::get().unwrap().unix_timestamp,
+        window_disputes: 0,
+        bump,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct AddProtocol<'info> {
+    #[account(
+        init,
+        seeds = ["protocol".as_bytes(), court.key().as_ref(), protocol.key().as_ref()],
+        bump,
+        payer = authority,
+        space = ProtocolEntry::SIZE
+    )]
+    pub protocol_entry: Account<'info, ProtocolEntry>,
+
+    #[account(
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(
+        mut,
+        constraint = court.edit_authority == authority.key() @ InputError::InvalidEditAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    ///CHECK: protocol that will sign initialize_dispute
+    pub protocol: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,87 @@
+use crate::{error::InputError, events::CourtChangeApplied, state::*, tools::records::check_queue_limit};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, CloseAccount, TokenAccount, TokenInterface};
+
+//permissionless - anyone can apply a queued edit_court change once it is effective
+
+pub fn apply_court_change(ctx: Context<ApplyCourtChange>, court_name: String) -> Result<()> {
+    let change = &ctx.accounts.court_change;
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+    require!(timestamp >= change.effective_at, InputError::CourtChangeNotEffective);
+
+    //records may have grown while the change was pending
+    let court = &mut ctx.accounts.court;
+    if change.max_dispute_votes < court.max_dispute_votes {
+        check_queue_limit(&court.key(), court.live_records, change.max_dispute_votes, ctx.remaining_accounts, ctx.program_id)?;
+    }
+
+    //juror stake sits in a vault of the current rep mint, so the mint can only be swapped once it is empty
+    //the empty vault is closed, deposit_stake then recreates it in the new mint
+    let stake_vault = ctx.accounts.stake_vault.to_account_info();
+    if change.rep_mint != court.rep_mint && !stake_vault.data_is_empty() {
+        let balance = InterfaceAccount::<TokenAccount>::try_from(&stake_vault)?.amount;
+        require!(balance == 0, InputError::StakeVaultNotEmpty);
+
+        let court_seeds: &[&[&[u8]]] = &[
+            &[
+                "court".as_bytes(),
+                court_name.as_bytes(),
+                &[court.bump]
+            ]
+        ];
+
+        token_interface::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: stake_vault,
+                destination: ctx.accounts.proposer.to_account_info(),
+                authority: court.to_account_info(),
+            },
+            court_seeds,
+        ))?;
+    }
+
+    court.edit(change);
+
+    emit!(CourtChangeApplied {
+        court: court.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct ApplyCourtChange<'info> {
+    #[account(
+        mut,
+        close = proposer,
+        seeds = ["court_change".as_bytes(), court.key().as_ref()],
+        bump = court_change.bump,
+    )]
+    pub court_change: Account<'info, PendingCourtChange>,
+
+    #[account(
+        mut,
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    ///CHECK: receives the change's rent, checked against court_change.proposer
+    #[account(
+        mut,
+        address = court_change.proposer
+    )]
+    pub proposer: UncheckedAccount<'info>,
+
+    ///CHECK: may not exist yet - only read (and closed) when the change swaps the rep mint
+    #[account(
+        mut,
+        seeds = ["stake_vault".as_bytes(), court.key().as_ref()],
+        bump,
+    )]
+    pub stake_vault: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
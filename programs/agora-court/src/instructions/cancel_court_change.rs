@@ -0,0 +1,42 @@
+use crate::{error::InputError, events::CourtChangeCancelled, state::*};
+use anchor_lang::prelude::*;
+
+//drops a queued edit_court change, any time before it is applied
+
+pub fn cancel_court_change(ctx: Context<CancelCourtChange>, _court_name: String) -> Result<()> {
+    emit!(CourtChangeCancelled {
+        court: ctx.accounts.court.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct CancelCourtChange<'info> {
+    #[account(
+        mut,
+        close = proposer,
+        seeds = ["court_change".as_bytes(), court.key().as_ref()],
+        bump = court_change.bump,
+    )]
+    pub court_change: Account<'info, PendingCourtChange>,
+
+    #[account(
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(
+        constraint = court.edit_authority == authority.key() @ InputError::InvalidEditAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    ///CHECK: receives the change's rent, checked against court_change.proposer
+    #[account(
+        mut,
+        address = court_change.proposer
+    )]
+    pub proposer: UncheckedAccount<'info>,
+}
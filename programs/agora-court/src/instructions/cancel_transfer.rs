@@ -0,0 +1,30 @@
+use crate::{error::InputError, state::*};
+use anchor_lang::prelude::*;
+
+//drops both pending proposals before they are accepted
+
+pub fn cancel_transfer(ctx: Context<CancelTransfer>, _court_name: String) -> Result<()> {
+    let court = &mut ctx.accounts.court;
+    require!(court.pending_authority.is_some() || court.pending_protocol.is_some(), InputError::NoPendingTransfer);
+
+    court.pending_authority = None;
+    court.pending_protocol = None;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct CancelTransfer<'info> {
+    #[account(
+        mut,
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(
+        constraint = court.edit_authority == authority.key() @ InputError::InvalidEditAuthority
+    )]
+    pub authority: Signer<'info>,
+}
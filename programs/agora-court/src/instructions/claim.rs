@@ -1,68 +1,80 @@
-use crate::{error::InputError, state::*};
+use crate::{error::InputError, state::*, tools::{reputation::*, payout::*, token::withdraw, native::withdraw_lamports}};
 use anchor_lang::prelude::*;
 
-use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{transfer, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 //default settings -> see afterthought in vote.rs for future possibilities
 
-//default: winning user gets full stake back, but no reward
+//default: winning user gets full stake back (plus rounding dust, see tools/payout.rs), but no reward
 //losers (parties and voters) do not get anything back
 //winning voters divide up the pool evenly, hence the incentive to vote
+//voter stakes are locked juror stake (see deposit_stake.rs) - claiming releases the lock on the record
 
 //To-Do:
 //TIES ARE NOT YET HANDLED
-//Protocol needs way to withdraw provided tokens, impl through a user
+//Protocol gets provided tokens back only when there is no winner, see refund_protocol.rs
 
 //of course, for v2, need to prevent public visibility of current vote counts
-//the claim queue only holds timestamps, not the status enum, which may delay claims unnecessarily.
+//any concluded dispute in the record can be claimed by id, in any order.
+
+//what a single claim owes the user - shared by claim and claim_many
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Settlement {
+    pub payout: Payout,   // paid out of the dispute vaults
+    pub rep_to_mint: u64, // soulbound courts mint/burn rep instead of paying it out of the vault
+    pub rep_to_burn: u64,
+}
 
-pub fn claim(ctx: Context<Claim>, _court_name: String, _dispute_id: u64) -> Result<()> {
-    let dispute = &mut ctx.accounts.dispute;
-    let dispute_rep_ata = &mut ctx.accounts.rep_vault;
-    let voter_record = &mut ctx.accounts.voter_record;
+//removes the dispute from the record and settles stakes, no transfers
+pub fn settle_claim(
+    dispute: &Dispute,
+    voter_record: &mut VoterRecord,
+    user: Pubkey,
+    dispute_id: u64
+) -> Result<Settlement> {
+    let involved_with = voter_record.remove(dispute_id).ok_or(InputError::UserCannotClaimDispute)?.user_voted_for;
 
-    let payer = &mut ctx.accounts.user;
-    let involved_with = voter_record.pop().unwrap().user_voted_for;
+    let calculator = PayoutCalculator::new(dispute);
+    let party_stake = calculator.refund_party(dispute.party_cost(user).unwrap_or_default());
+    let voter_stake = calculator.voter_stake();
 
-    let mut rep_amount_to_transfer = dispute.config.rep_cost;
-    let mut pay_amount_to_transfer = dispute.config.pay_cost;
+    let mut settlement = Settlement::default();
 
     match dispute.status {
         DisputeStatus::Concluded { winner: Some(x) } => {
-            if x == payer.key() {
-                //winning party => refund arb_cost
-                voter_record.currently_staked_pay -= pay_amount_to_transfer;
-                voter_record.currently_staked_rep -= rep_amount_to_transfer;
-            } else if dispute.users.contains(&Some(payer.key())) {
+            if x == user {
+                //winning party => refund arb_cost + payout dust, voter stake dust is credited on the juror ledger
+                voter_record.unstake(party_stake.rep, party_stake.pay)?;
+                voter_record.juror_stake.deposit(calculator.winning_party_stake()?)?;
+                settlement.payout = calculator.winning_party()?;
+            } else if dispute.users.contains(&Some(user)) {
                 //losing party -= voter_record
-                voter_record.currently_staked_pay -= pay_amount_to_transfer;
-                voter_record.currently_staked_rep -= rep_amount_to_transfer;
-                return Ok(());
+                voter_record.unstake(party_stake.rep, party_stake.pay)?;
             } else if voter_record.verify_key(involved_with, x) {
                 //winning voter reward
-                //i know this literally doesn't check overflow at all - the other subtractions shouldn't matter for overflow/underflow?
-                voter_record.currently_staked_rep -= dispute.config.voter_rep_cost;
+                voter_record.juror_stake.release(voter_stake, calculator.winning_voter_stake()?)?;
+                settlement.payout = calculator.winning_voter()?;
 
-                rep_amount_to_transfer = (((dispute.submitted_cases as u64 - 1) * dispute.config.rep_cost) + dispute.config.protocol_rep + (dispute.total_votes() * dispute.config.voter_rep_cost)) / dispute.leader_votes();
-                pay_amount_to_transfer = (((dispute.submitted_cases as u64 - 1) * dispute.config.pay_cost) + dispute.config.protocol_pay) / dispute.leader_votes();
+                if let Some(soulbound) = &dispute.soulbound {
+                    settlement.rep_to_mint = soulbound.coherent_reward;
+                }
             } else {
-                //losing voter -= voter_record
-                voter_record.currently_staked_rep -= dispute.config.voter_rep_cost;
-                return Ok(());
+                //losing voter or one who never revealed -= voter_record, locked stake goes to the coherent voters
+                voter_record.juror_stake.release(voter_stake, 0)?;
+
+                if let Some(soulbound) = &dispute.soulbound {
+                    settlement.rep_to_burn = soulbound.incoherent_slash;
+                }
             }
         },
         DisputeStatus::Concluded { winner: None } => {
-            if dispute.users.contains(&Some(payer.key())) {
+            if dispute.users.contains(&Some(user)) {
                 //refund arb_cost
-                voter_record.currently_staked_pay -= pay_amount_to_transfer;
-                voter_record.currently_staked_rep -= rep_amount_to_transfer;
+                voter_record.unstake(party_stake.rep, party_stake.pay)?;
+                settlement.payout = party_stake;
             } else {
                 // refund voters
-                rep_amount_to_transfer = dispute.config.voter_rep_cost;
-                pay_amount_to_transfer = 0;
-
-                voter_record.currently_staked_rep -= rep_amount_to_transfer;
+                voter_record.juror_stake.release(voter_stake, voter_stake)?;
             }
         },
         _ => {
@@ -70,8 +82,68 @@ pub fn claim(ctx: Context<Claim>, _court_name: String, _dispute_id: u64) -> Resu
         }
     }
 
+    Ok(settlement)
+}
+
+pub fn claim(ctx: Context<Claim>, court_name: String, _dispute_id: u64) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let dispute_rep_ata = &mut ctx.accounts.rep_vault;
+    let voter_record = &mut ctx.accounts.voter_record;
+    let payer = &mut ctx.accounts.user;
+
+    let Settlement { payout, rep_to_mint, rep_to_burn } = settle_claim(
+        dispute,
+        voter_record,
+        payer.key(),
+        _dispute_id
+    )?;
+    ctx.accounts.court.track_record(true, !voter_record.claim_queue.is_empty());
+
+    let rep_amount_to_transfer = payout.rep;
+    let pay_amount_to_transfer = payout.pay;
+
     msg!("rep to transfer: {}, pay to transfer: {}", rep_amount_to_transfer, pay_amount_to_transfer);
 
+    //settle any debt left by crank_claim along with this dispute's mint / burn
+    let rep_to_burn = rep_to_burn.checked_add(voter_record.rep_debt).ok_or(InputError::PayoutOverflow)?;
+    voter_record.rep_debt = 0;
+
+    if rep_to_mint != rep_to_burn {
+        let court = &ctx.accounts.court;
+        let court_seeds: &[&[&[u8]]] = &[
+            &[
+                "court".as_bytes(),
+                court_name.as_bytes(),
+                &[court.bump]
+            ]
+        ];
+
+        if let Some(acc) = &ctx.accounts.user_rep_ata {
+            if rep_to_mint > rep_to_burn {
+                mint_soulbound(
+                    &ctx.accounts.rep_token_program.to_account_info(),
+                    &ctx.accounts.rep_mint.to_account_info(),
+                    acc,
+                    &court.to_account_info(),
+                    court_seeds,
+                    rep_to_mint - rep_to_burn
+                )?;
+            } else {
+                burn_soulbound(
+                    &ctx.accounts.rep_token_program.to_account_info(),
+                    &ctx.accounts.rep_mint.to_account_info(),
+                    acc,
+                    &payer.to_account_info(),
+                    &court.to_account_info(),
+                    court_seeds,
+                    rep_to_burn - rep_to_mint
+                )?;
+            }
+        } else {
+            return err!(InputError::ReputationAtaMissing);
+        }
+    }
+
 
     //refund arb_cost - two cases
     let court_key = ctx.accounts.court.key();
@@ -89,40 +161,42 @@ pub fn claim(ctx: Context<Claim>, _court_name: String, _dispute_id: u64) -> Resu
         let user_ata = &mut ctx.accounts.user_rep_ata;
 
         if let Some(acc) = user_ata {
-            let cpi_ctx = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: dispute_rep_ata.to_account_info(),
-                    to: acc.to_account_info(),
-                    authority: dispute.to_account_info()
-                },
-                signer_seeds
-            );
-
-            transfer(cpi_ctx, rep_amount_to_transfer)?;
+            withdraw(
+                &ctx.accounts.rep_token_program.to_account_info(),
+                dispute_rep_ata,
+                acc,
+                &ctx.accounts.rep_mint,
+                &dispute.to_account_info(),
+                signer_seeds,
+                rep_amount_to_transfer
+            )?;
         } else {
             return err!(InputError::ReputationAtaMissing);
         }
     }
 
-    if pay_amount_to_transfer > 0 {
+    if pay_amount_to_transfer > 0 && dispute.pays_native() {
+        withdraw_lamports(
+            &dispute.to_account_info(),
+            &payer.to_account_info(),
+            pay_amount_to_transfer
+        )?;
+    } else if pay_amount_to_transfer > 0 {
         let user_ata = &mut ctx.accounts.user_pay_ata;
         let vault_ata = &mut ctx.accounts.pay_vault;
 
-        if let (Some(user_acc), Some(vault_acc), Some(mint), Some(mint_acc)) = (user_ata, vault_ata, &ctx.accounts.court.pay_mint, &ctx.accounts.pay_mint) {
+        if let (Some(user_acc), Some(vault_acc), Some(mint), Some(mint_acc)) = (user_ata, vault_ata, &dispute.pay_mint, &ctx.accounts.pay_mint) {
             require!(mint_acc.key() == *mint, InputError::ProtocolMintMismatch);
 
-            let cpi_ctx = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: vault_acc.to_account_info(),
-                    to: user_acc.to_account_info(),
-                    authority: dispute.to_account_info()
-                },
-                signer_seeds
-            );
-
-            transfer(cpi_ctx, pay_amount_to_transfer)?;
+            withdraw(
+                &ctx.accounts.pay_token_program.to_account_info(),
+                vault_acc,
+                user_acc,
+                mint_acc,
+                &dispute.to_account_info(),
+                signer_seeds,
+                pay_amount_to_transfer
+            )?;
         } else {
             return err!(InputError::PaymentAtaMissing);
         }
@@ -139,9 +213,7 @@ pub struct Claim<'info> {
         mut,
         seeds = ["record".as_bytes(), court.key().as_ref(), user.key().as_ref()],
         bump = voter_record.bump,
-        constraint = voter_record.has_unclaimed_disputes()
-                    @ InputError::UserHasNoUnclaimedDisputes,
-        constraint = voter_record.peek().unwrap().dispute_id == _dispute_id
+        constraint = voter_record.in_dispute(_dispute_id)
                     @ InputError::UserCannotClaimDispute,
     )]
     pub voter_record: Box<Account<'info, VoterRecord>>,
@@ -155,19 +227,22 @@ pub struct Claim<'info> {
 
     #[account(
         mut,
-        associated_token::mint = rep_mint,
-        associated_token::authority = dispute
+        seeds = ["rep_vault".as_bytes(), dispute.key().as_ref()],
+        bump,
+        token::mint = rep_mint
     )]
-    pub rep_vault: Box<Account<'info, TokenAccount>>,
+    pub rep_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
-        associated_token::mint = pay_mint,
-        associated_token::authority = dispute
+        seeds = ["pay_vault".as_bytes(), dispute.key().as_ref()],
+        bump,
+        token::mint = pay_mint
     )]
-    pub pay_vault: Option<Account<'info, TokenAccount>>,
+    pub pay_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
+        mut,
         seeds = ["court".as_bytes(), _court_name.as_bytes()],
         bump = court.bump,
     )]
@@ -178,26 +253,28 @@ pub struct Claim<'info> {
 
     #[account(
         mut,
-        associated_token::mint = pay_mint,
-        associated_token::authority = user
+        token::mint = pay_mint,
+        token::authority = user
     )]
-    pub user_pay_ata: Option<Account<'info, TokenAccount>>,
+    pub user_pay_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
         mut,
-        associated_token::mint = rep_mint,
-        associated_token::authority = user,
+        token::mint = rep_mint,
+        token::authority = user,
     )]
-    pub user_rep_ata: Option<Account<'info, TokenAccount>>,
+    pub user_rep_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
-        constraint = rep_mint.key() == court.rep_mint @ InputError::ReputationMintMismatch
+        mut,
+        constraint = rep_mint.key() == dispute.rep_mint @ InputError::ReputationMintMismatch
     )]
-    pub rep_mint: Box<Account<'info, Mint>>,
+    pub rep_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub pay_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
 
-    pub pay_mint: Option<Account<'info, Mint>>,
+    pub rep_token_program: Interface<'info, TokenInterface>,
 
-    pub token_program: Program<'info, Token>,
+    pub pay_token_program: Interface<'info, TokenInterface>, // same as rep_token_program when the court has no pay mint
     pub system_program: Program<'info, System>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
 }
\ No newline at end of file
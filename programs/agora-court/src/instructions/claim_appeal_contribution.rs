@@ -0,0 +1,219 @@
+use crate::{error::InputError, state::*, tools::{payout::AppealCalculator, token::withdraw, native::withdraw_lamports}};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+//settles one contribution, closing its account back to the contributor
+//  funding closed and no side reached the bond => full refund
+//  a funded appeal was not opened in time     => full refund
+//  once opened, each fully funded side's bond sits on the appeal dispute and the rest in the fund
+//  (see tools/payout.rs):
+//  appeal concluded without a winner         => full refund, from wherever the contribution is held
+//  contributor backed the appeal's winner    => contribution + bonus, the bonus out of the fund
+//  contributor backed a losing side          => a spent bond paid the voters, otherwise whatever the bonuses left
+
+pub fn claim_appeal_contribution(
+    ctx: Context<ClaimAppealContribution>,
+    _court_name: String,
+    _dispute_id: u64,
+    side: u8
+) -> Result<()> {
+    let fund = &ctx.accounts.appeal_fund;
+    let amount = ctx.accounts.contribution.amount;
+    let side = side as usize;
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    //(owed off the appeal dispute, owed out of the fund)
+    let (from_appeal, from_fund) = match fund.status {
+        AppealStatus::Funding => {
+            require!(fund.expired(timestamp), InputError::AppealNotResolved);
+            (0, amount)
+        },
+        AppealStatus::Appealed { dispute_id } => {
+            //escalated appeals run in the parent court
+            let appeal = ctx.accounts.appeal_dispute.as_ref().ok_or(InputError::AppealDisputeMismatch)?;
+            let expected = Pubkey::create_program_address(
+                &[
+                    "dispute".as_bytes(),
+                    fund.target_court.as_ref(),
+                    dispute_id.to_be_bytes().as_ref(),
+                    &[appeal.bump]
+                ],
+                ctx.program_id
+            ).map_err(|_| InputError::AppealDisputeMismatch)?;
+            require!(appeal.key() == expected, InputError::AppealDisputeMismatch);
+
+            let winning_side = match appeal.status {
+                DisputeStatus::Concluded { winner: Some(x) } => fund.parties.iter().position(|&p| p == Some(x)),
+                DisputeStatus::Concluded { winner: None } => None,
+                _ => return err!(InputError::AppealNotResolved),
+            };
+
+            //a spent bond is returned as what arrived on the appeal dispute, net of any transfer fee
+            let spent = fund.spent(side);
+            let principal = if spent {
+                AppealCalculator::pro_rata(amount, appeal.appeal_bonds[side], fund.funded[side])
+            } else {
+                amount
+            };
+
+            match winning_side {
+                Some(winning_side) => {
+                    let calculator = AppealCalculator {
+                        winning_total: fund.funded[winning_side],
+                        unspent_losing: fund.unspent_losing_total(winning_side),
+                        bonus_bps: fund.config.bonus_bps,
+                    };
+
+                    match (side == winning_side, spent) {
+                        (true, true) => (principal, calculator.winning_bonus(amount)),
+                        (true, false) => (0, principal + calculator.winning_bonus(amount)),
+                        (false, true) => (0, 0),
+                        (false, false) => (0, calculator.losing_contributor(amount)),
+                    }
+                },
+                None if spent => (principal, 0),
+                None => (0, principal),
+            }
+        },
+    };
+
+    msg!("appeal payout: {} from the appeal dispute, {} from the fund", from_appeal, from_fund);
+
+    if from_appeal > 0 {
+        let appeal = ctx.accounts.appeal_dispute.as_ref().ok_or(InputError::AppealDisputeMismatch)?;
+
+        if appeal.pays_native() {
+            withdraw_lamports(
+                &appeal.to_account_info(),
+                &ctx.accounts.contributor.to_account_info(),
+                from_appeal
+            )?;
+        } else if let (Some(user_acc), Some(vault_acc), Some(mint_acc)) = (&ctx.accounts.contributor_pay_ata, &ctx.accounts.appeal_pay_vault, &ctx.accounts.pay_mint) {
+            let (expected, _) = Pubkey::find_program_address(&["pay_vault".as_bytes(), appeal.key().as_ref()], ctx.program_id);
+            require!(vault_acc.key() == expected, InputError::ClaimAccountsMismatch);
+
+            let id_bytes = match ctx.accounts.appeal_fund.status {
+                AppealStatus::Appealed { dispute_id } => dispute_id.to_be_bytes(),
+                AppealStatus::Funding => return err!(InputError::AppealNotResolved),
+            };
+            let target_court = ctx.accounts.appeal_fund.target_court;
+            let signer_seeds: &[&[&[u8]]] = &[
+                &[
+                    "dispute".as_bytes(),
+                    target_court.as_ref(),
+                    id_bytes.as_ref(),
+                    &[appeal.bump]
+                ]
+            ];
+
+            withdraw(
+                &ctx.accounts.token_program.to_account_info(),
+                vault_acc,
+                user_acc,
+                mint_acc,
+                &appeal.to_account_info(),
+                signer_seeds,
+                from_appeal
+            )?;
+        } else {
+            return err!(InputError::PaymentAtaMissing);
+        }
+    }
+
+    if from_fund == 0 {
+        return Ok(());
+    }
+
+    if ctx.accounts.dispute.pays_native() {
+        withdraw_lamports(
+            &ctx.accounts.appeal_fund.to_account_info(),
+            &ctx.accounts.contributor.to_account_info(),
+            from_fund
+        )?;
+    } else if let (Some(user_acc), Some(vault_acc), Some(mint_acc)) = (&ctx.accounts.contributor_pay_ata, &ctx.accounts.appeal_vault, &ctx.accounts.pay_mint) {
+        let dispute_key = ctx.accounts.dispute.key();
+        let signer_seeds: &[&[&[u8]]] = &[
+            &[
+                "appeal_fund".as_bytes(),
+                dispute_key.as_ref(),
+                &[ctx.accounts.appeal_fund.bump]
+            ]
+        ];
+
+        withdraw(
+            &ctx.accounts.token_program.to_account_info(),
+            vault_acc,
+            user_acc,
+            mint_acc,
+            &ctx.accounts.appeal_fund.to_account_info(),
+            signer_seeds,
+            from_fund
+        )?;
+    } else {
+        return err!(InputError::PaymentAtaMissing);
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String, _dispute_id: u64, side: u8)]
+pub struct ClaimAppealContribution<'info> {
+    #[account(
+        mut,
+        close = contributor,
+        seeds = ["appeal_contribution".as_bytes(), appeal_fund.key().as_ref(), contributor.key().as_ref(), &[side]],
+        bump = contribution.bump,
+    )]
+    pub contribution: Box<Account<'info, AppealContribution>>,
+
+    #[account(
+        mut,
+        seeds = ["appeal_fund".as_bytes(), dispute.key().as_ref()],
+        bump = appeal_fund.bump,
+    )]
+    pub appeal_fund: Box<Account<'info, AppealFund>>,
+
+    #[account(
+        mut,
+        seeds = ["appeal_vault".as_bytes(), dispute.key().as_ref()],
+        bump,
+        token::mint = pay_mint
+    )]
+    pub appeal_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        seeds = ["dispute".as_bytes(), court.key().as_ref(), _dispute_id.to_be_bytes().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    #[account(mut)]
+    pub appeal_dispute: Option<Box<Account<'info, Dispute>>>, // the new dispute the appeal runs as, once opened
+
+    #[account(mut)]
+    pub appeal_pay_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>, // the appeal dispute's pay vault, checked in the handler
+
+    #[account(
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump
+    )]
+    pub court: Box<Account<'info, Court>>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = pay_mint,
+        token::authority = contributor
+    )]
+    pub contributor_pay_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        constraint = Some(pay_mint.key()) == dispute.pay_mint @ InputError::ProtocolMintMismatch
+    )]
+    pub pay_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
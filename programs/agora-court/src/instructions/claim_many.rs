@@ -0,0 +1,186 @@
+use crate::{error::InputError, state::*, tools::{reputation::*, token::withdraw, native::withdraw_lamports}};
+use crate::instructions::claim::{settle_claim, Settlement};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+//claims several disputes in one transaction, settling them in the order given
+//remaining accounts hold 3 entries per dispute id: [dispute, rep_vault, pay_vault]
+//(pay_vault = program id when the court has no pay vault, same as anchor's None)
+//
+//vault payouts are one transfer per dispute and mint - every vault has its own dispute authority.
+//soulbound mints/burns are netted into a single CPI, together with any rep_debt left by crank_claim -
+//an empty id list only settles that debt.
+//every dispute must have been created with the rep_mint passed in.
+//~10 disputes fit with a raised compute budget, ~5 within the default.
+
+pub const ACCOUNTS_PER_CLAIM: usize = 3;
+
+pub fn claim_many<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimMany<'info>>,
+    court_name: String,
+    dispute_ids: Vec<u64>
+) -> Result<()> {
+    let rep_debt = ctx.accounts.voter_record.rep_debt;
+    require!(!dispute_ids.is_empty() || rep_debt > 0, InputError::ClaimListEmpty);
+    require!(rep_debt == 0 || ctx.accounts.rep_mint.key() == ctx.accounts.court.rep_mint, InputError::ReputationMintMismatch);
+    require!(ctx.remaining_accounts.len() == dispute_ids.len() * ACCOUNTS_PER_CLAIM, InputError::ClaimAccountsMismatch);
+
+    let court = &ctx.accounts.court;
+    let court_key = court.key();
+    let rep_token_program = ctx.accounts.rep_token_program.to_account_info();
+    let pay_token_program = ctx.accounts.pay_token_program.to_account_info();
+    let user = ctx.accounts.user.to_account_info();
+
+    let mut rep_to_mint: u64 = 0;
+    let mut rep_to_burn: u64 = rep_debt;
+    ctx.accounts.voter_record.rep_debt = 0;
+
+    for (i, &dispute_id) in dispute_ids.iter().enumerate() {
+        let accounts = &ctx.remaining_accounts[i * ACCOUNTS_PER_CLAIM..(i + 1) * ACCOUNTS_PER_CLAIM];
+        let dispute_info = &accounts[0];
+
+        let dispute: Account<'info, Dispute> = Account::try_from(dispute_info)?;
+        let id_bytes = dispute_id.to_be_bytes();
+        let dispute_seeds: &[&[u8]] = &["dispute".as_bytes(), court_key.as_ref(), id_bytes.as_ref(), &[dispute.bump]];
+        let expected = Pubkey::create_program_address(dispute_seeds, ctx.program_id)
+            .map_err(|_| InputError::ClaimAccountsMismatch)?;
+        require!(dispute.key() == expected, InputError::ClaimAccountsMismatch);
+        require!(dispute.rep_mint == ctx.accounts.rep_mint.key(), InputError::ReputationMintMismatch);
+
+        let Settlement { payout, rep_to_mint: mint, rep_to_burn: burn } = settle_claim(
+            &dispute,
+            &mut ctx.accounts.voter_record,
+            user.key(),
+            dispute_id
+        )?;
+
+        msg!("dispute {}: rep to transfer: {}, pay to transfer: {}", dispute_id, payout.rep, payout.pay);
+
+        rep_to_mint = rep_to_mint.checked_add(mint).ok_or(InputError::PayoutOverflow)?;
+        rep_to_burn = rep_to_burn.checked_add(burn).ok_or(InputError::PayoutOverflow)?;
+
+        let signer_seeds: &[&[&[u8]]] = &[dispute_seeds];
+
+        if payout.rep > 0 {
+            let user_acc = ctx.accounts.user_rep_ata.as_ref().ok_or(InputError::ReputationAtaMissing)?;
+            let vault: InterfaceAccount<'info, TokenAccount> = InterfaceAccount::try_from(&accounts[1])?;
+            require!(vault.owner == dispute.key() && vault.mint == dispute.rep_mint, InputError::ClaimAccountsMismatch);
+
+            withdraw(
+                &rep_token_program,
+                &vault,
+                user_acc,
+                &ctx.accounts.rep_mint,
+                dispute_info,
+                signer_seeds,
+                payout.rep
+            )?;
+        }
+
+        if payout.pay > 0 && dispute.pays_native() {
+            withdraw_lamports(dispute_info, &user, payout.pay)?;
+        } else if payout.pay > 0 {
+            if let (Some(user_acc), Some(mint_acc)) = (&ctx.accounts.user_pay_ata, &ctx.accounts.pay_mint) {
+                require!(Some(mint_acc.key()) == dispute.pay_mint, InputError::ProtocolMintMismatch);
+
+                let vault: InterfaceAccount<'info, TokenAccount> = InterfaceAccount::try_from(&accounts[2])?;
+                require!(vault.owner == dispute.key() && vault.mint == mint_acc.key(), InputError::ClaimAccountsMismatch);
+
+                withdraw(
+                    &pay_token_program,
+                    &vault,
+                    user_acc,
+                    mint_acc,
+                    dispute_info,
+                    signer_seeds,
+                    payout.pay
+                )?;
+            } else {
+                return err!(InputError::PaymentAtaMissing);
+            }
+        }
+    }
+
+    //net the soulbound mints and burns into one CPI
+    if rep_to_mint != rep_to_burn {
+        let acc = ctx.accounts.user_rep_ata.as_ref().ok_or(InputError::ReputationAtaMissing)?;
+        let court_seeds: &[&[&[u8]]] = &[
+            &[
+                "court".as_bytes(),
+                court_name.as_bytes(),
+                &[court.bump]
+            ]
+        ];
+
+        if rep_to_mint > rep_to_burn {
+            mint_soulbound(
+                &rep_token_program,
+                &ctx.accounts.rep_mint.to_account_info(),
+                acc,
+                &court.to_account_info(),
+                court_seeds,
+                rep_to_mint - rep_to_burn
+            )?;
+        } else {
+            burn_soulbound(
+                &rep_token_program,
+                &ctx.accounts.rep_mint.to_account_info(),
+                acc,
+                &user,
+                &court.to_account_info(),
+                court_seeds,
+                rep_to_burn - rep_to_mint
+            )?;
+        }
+    }
+
+    let is_live = !ctx.accounts.voter_record.claim_queue.is_empty();
+    ctx.accounts.court.track_record(true, is_live);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct ClaimMany<'info> {
+    #[account(
+        mut,
+        seeds = ["record".as_bytes(), court.key().as_ref(), user.key().as_ref()],
+        bump = voter_record.bump,
+    )]
+    pub voter_record: Box<Account<'info, VoterRecord>>,
+
+    #[account(
+        mut,
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Box<Account<'info, Court>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = pay_mint,
+        token::authority = user
+    )]
+    pub user_pay_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = rep_mint,
+        token::authority = user,
+    )]
+    pub user_rep_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub rep_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub pay_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    pub rep_token_program: Interface<'info, TokenInterface>,
+
+    pub pay_token_program: Interface<'info, TokenInterface>, // same as rep_token_program when the court has no pay mint
+    pub system_program: Program<'info, System>,
+}
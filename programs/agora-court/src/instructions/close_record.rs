@@ -0,0 +1,37 @@
+use crate::{error::InputError, events::RecordClosed, state::{VoterRecord, Court}};
+use anchor_lang::prelude::*;
+
+//lets a user leaving a court get their record's rent back
+//only once every dispute has been claimed and all stake (party and juror) has been returned
+
+pub fn close_record(ctx: Context<CloseRecord>, _court_name: String) -> Result<()> {
+    emit!(RecordClosed {
+        court: ctx.accounts.court.key(),
+        owner: ctx.accounts.payer.key(),
+        lamports: ctx.accounts.record.to_account_info().lamports(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct CloseRecord<'info> {
+    #[account(
+        mut,
+        close = payer,
+        seeds = ["record".as_bytes(), court.key().as_ref(), payer.key().as_ref()],
+        bump = record.bump,
+        constraint = record.is_settled() @ InputError::RecordNotSettled,
+    )]
+    pub record: Account<'info, VoterRecord>,
+
+    #[account(
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>, // record owner
+}
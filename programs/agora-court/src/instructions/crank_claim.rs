@@ -0,0 +1,222 @@
+use crate::{error::InputError, state::*, tools::{reputation::mint_soulbound, token::{owner_ata, withdraw}, native::{withdraw_lamports, pay_from_pda}}};
+use crate::instructions::claim::{settle_claim, Settlement};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+//permissionless claim - any keeper can settle a concluded dispute for a record owner
+//tokens always go to the owner's ATA (created by the keeper if needed), lamports to the owner.
+//the keeper earns court.crank_tip from the court treasury PDA, skipped if the treasury can't cover it.
+//soulbound slashes burn from the owner's account and need their signature, so they are added to the
+//record's rep_debt instead - netted against later cranked mints, burned on the owner's next signed claim.
+
+pub fn crank_claim(ctx: Context<CrankClaim>, court_name: String, dispute_id: u64) -> Result<()> {
+    let voter_record = &mut ctx.accounts.voter_record;
+    let Settlement { payout, rep_to_mint, rep_to_burn } = settle_claim(
+        &ctx.accounts.dispute,
+        voter_record,
+        ctx.accounts.owner.key(),
+        dispute_id
+    )?;
+    ctx.accounts.court.track_record(true, !voter_record.claim_queue.is_empty());
+
+    voter_record.rep_debt = voter_record.rep_debt.checked_add(rep_to_burn).ok_or(InputError::PayoutOverflow)?;
+    let rep_to_mint = voter_record.offset_rep_debt(rep_to_mint);
+
+    msg!("rep to transfer: {}, pay to transfer: {}", payout.rep, payout.pay);
+
+    let keeper = ctx.accounts.keeper.to_account_info();
+    let owner = ctx.accounts.owner.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let rep_token_program = ctx.accounts.rep_token_program.to_account_info();
+    let pay_token_program = ctx.accounts.pay_token_program.to_account_info();
+    let associated_token_program = ctx.accounts.associated_token_program.to_account_info();
+
+    let court = &ctx.accounts.court;
+    let court_key = court.key();
+    let dispute = &ctx.accounts.dispute;
+    let id_bytes = dispute_id.to_be_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[
+        &[
+            "dispute".as_bytes(),
+            court_key.as_ref(),
+            id_bytes.as_ref(),
+            &[dispute.bump]
+        ]
+    ];
+
+    if payout.rep > 0 || rep_to_mint > 0 {
+        let owner_rep_ata = owner_ata(
+            &keeper,
+            &ctx.accounts.owner_rep_ata,
+            &owner,
+            &ctx.accounts.rep_mint,
+            &system_program,
+            &rep_token_program,
+            &associated_token_program
+        )?;
+
+        if rep_to_mint > 0 {
+            let court_seeds: &[&[&[u8]]] = &[
+                &[
+                    "court".as_bytes(),
+                    court_name.as_bytes(),
+                    &[court.bump]
+                ]
+            ];
+
+            mint_soulbound(
+                &rep_token_program,
+                &ctx.accounts.rep_mint.to_account_info(),
+                &owner_rep_ata,
+                &court.to_account_info(),
+                court_seeds,
+                rep_to_mint
+            )?;
+        }
+
+        if payout.rep > 0 {
+            withdraw(
+                &rep_token_program,
+                &ctx.accounts.rep_vault,
+                &owner_rep_ata,
+                &ctx.accounts.rep_mint,
+                &dispute.to_account_info(),
+                signer_seeds,
+                payout.rep
+            )?;
+        }
+    }
+
+    if payout.pay > 0 && dispute.pays_native() {
+        withdraw_lamports(
+            &dispute.to_account_info(),
+            &owner,
+            payout.pay
+        )?;
+    } else if payout.pay > 0 {
+        if let (Some(ata), Some(vault_acc), Some(mint_acc)) = (&ctx.accounts.owner_pay_ata, &ctx.accounts.pay_vault, &ctx.accounts.pay_mint) {
+            require!(Some(mint_acc.key()) == dispute.pay_mint, InputError::ProtocolMintMismatch);
+
+            let owner_pay_ata = owner_ata(
+                &keeper,
+                ata,
+                &owner,
+                mint_acc,
+                &system_program,
+                &pay_token_program,
+                &associated_token_program
+            )?;
+
+            withdraw(
+                &pay_token_program,
+                vault_acc,
+                &owner_pay_ata,
+                mint_acc,
+                &dispute.to_account_info(),
+                signer_seeds,
+                payout.pay
+            )?;
+        } else {
+            return err!(InputError::PaymentAtaMissing);
+        }
+    }
+
+    //keeper tip
+    let treasury_bump = *ctx.bumps.get("treasury").unwrap();
+    let treasury_seeds: &[&[&[u8]]] = &[
+        &[
+            "treasury".as_bytes(),
+            court_key.as_ref(),
+            &[treasury_bump]
+        ]
+    ];
+    let tip = pay_from_pda(
+        &system_program,
+        &ctx.accounts.treasury.to_account_info(),
+        &keeper,
+        treasury_seeds,
+        court.crank_tip
+    )?;
+    msg!("keeper tip: {}", tip);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String, dispute_id: u64)]
+pub struct CrankClaim<'info> {
+    #[account(
+        mut,
+        seeds = ["record".as_bytes(), court.key().as_ref(), owner.key().as_ref()],
+        bump = voter_record.bump,
+        constraint = voter_record.in_dispute(dispute_id)
+                    @ InputError::UserCannotClaimDispute,
+    )]
+    pub voter_record: Box<Account<'info, VoterRecord>>,
+
+    #[account(
+        mut,
+        seeds = ["dispute".as_bytes(), court.key().as_ref(), dispute_id.to_be_bytes().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    #[account(
+        mut,
+        seeds = ["rep_vault".as_bytes(), dispute.key().as_ref()],
+        bump,
+        token::mint = rep_mint
+    )]
+    pub rep_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = ["pay_vault".as_bytes(), dispute.key().as_ref()],
+        bump,
+        token::mint = pay_mint
+    )]
+    pub pay_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Box<Account<'info, Court>>,
+
+    #[account(
+        mut,
+        seeds = ["treasury".as_bytes(), court.key().as_ref()],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>, // funded by plain SOL transfers
+
+    ///CHECK: record owner, only receives funds - the record seeds tie it to the voter record
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    ///CHECK: owner's rep ATA, address checked and created if needed in the handler
+    #[account(mut)]
+    pub owner_rep_ata: UncheckedAccount<'info>,
+
+    ///CHECK: owner's pay ATA, address checked and created if needed in the handler
+    #[account(mut)]
+    pub owner_pay_ata: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        constraint = rep_mint.key() == dispute.rep_mint @ InputError::ReputationMintMismatch
+    )]
+    pub rep_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub pay_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    pub rep_token_program: Interface<'info, TokenInterface>,
+    pub pay_token_program: Interface<'info, TokenInterface>, // same as rep_token_program when the court has no pay mint
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,69 @@
+use crate::{error::InputError, state::{voter_record::VoterRecord, Court}};
+use anchor_lang::prelude::*;
+use crate::tools::token::deposit;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+//jurors stake rep once into the court-wide stake vault
+//select_vote then locks part of the free stake per dispute instead of transferring tokens each time
+
+pub fn deposit_stake(ctx: Context<DepositStake>, _court_name: String, amount: u64) -> Result<()> {
+    //soulbound rep is frozen in user accounts, eligibility there is checked against the wallet
+    require!(ctx.accounts.court.soulbound.is_none(), InputError::SoulboundReputationNotTransferable);
+
+    deposit(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.user_rep_ata,
+        &mut ctx.accounts.stake_vault,
+        &ctx.accounts.rep_mint,
+        &ctx.accounts.user.to_account_info(),
+        amount
+    )?;
+
+    ctx.accounts.record.juror_stake.deposit(amount)
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct DepositStake<'info> {
+    #[account(
+        mut,
+        seeds = ["record".as_bytes(), court.key().as_ref(), user.key().as_ref()],
+        bump = record.bump,
+    )]
+    pub record: Box<Account<'info, VoterRecord>>,
+
+    #[account(
+        init_if_needed,
+        seeds = ["stake_vault".as_bytes(), court.key().as_ref()],
+        bump,
+        payer = user,
+        token::mint = rep_mint,
+        token::authority = court
+    )]
+    pub stake_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Box<Account<'info, Court>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = rep_mint,
+        token::authority = user,
+    )]
+    pub user_rep_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = rep_mint.key() == court.rep_mint @ InputError::ReputationMintMismatch
+    )]
+    pub rep_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
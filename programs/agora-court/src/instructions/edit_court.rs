@@ -1,33 +1,74 @@
-use crate::{error::InputError, state::Court};
+use crate::{error::InputError, events::CourtChangeQueued, state::*, tools::records::check_queue_limit};
 use anchor_lang::prelude::*;
-use anchor_spl::token::Mint;
+use anchor_spl::token_interface::Mint;
+
+//queues new court settings - they take effect through apply_court_change after court.change_delay,
+//so jurors and parties get time to leave first. one change can be pending at a time (see cancel_court_change)
 
 pub fn edit_court(
     ctx: Context<EditCourt>, 
     _court_name: String, 
-    max_dispute_votes: u16
+    max_dispute_votes: u16,
+    unbonding_period: i64,
+    crank_tip: u64,
+    change_delay: i64
 ) -> Result<()> {
-    let court = &mut ctx.accounts.court;
+    let court = &ctx.accounts.court;
+    require!(unbonding_period >= 0, InputError::InvalidUnbondingPeriod);
+    require!(change_delay >= 0, InputError::InvalidChangeDelay);
+
+    //shrinking is checked against the live records passed in - checked again on apply
+    if max_dispute_votes < court.max_dispute_votes {
+        check_queue_limit(&court.key(), court.live_records, max_dispute_votes, ctx.remaining_accounts, ctx.program_id)?;
+    }
+
+    //soulbound rep lives in a court-owned mint, swapping it out would orphan everyone's reputation
+    if court.soulbound.is_some() {
+        require!(ctx.accounts.rep_mint.key() == court.rep_mint, InputError::SoulboundMintLocked);
+    }
+
+    let effective_at = Clock::get().unwrap().unix_timestamp.checked_add(court.change_delay).ok_or(InputError::InvalidChangeDelay)?;
+    let bump = *ctx.bumps.get("court_change").unwrap();
+    let change = &mut ctx.accounts.court_change;
+    change.set_inner(PendingCourtChange {
+        court: court.key(),
+        proposer: ctx.accounts.authority.key(),
+        max_dispute_votes,
+        unbonding_period,
+        crank_tip,
+        change_delay,
+        rep_mint: ctx.accounts.rep_mint.key(),
+        pay_mint: ctx.accounts.pay_mint.as_ref().map(|n| n.key()),
+        effective_at,
+        bump,
+    });
 
-    court.edit(
+    emit!(CourtChangeQueued {
+        court: court.key(),
         max_dispute_votes,
-        ctx.accounts.transfer_authority.key(),
-        ctx.accounts.transfer_protocol.key(),
-        ctx.accounts.rep_mint.key(),
-        &ctx.accounts.pay_mint
-    );
+        unbonding_period,
+        crank_tip,
+        change_delay,
+        rep_mint: change.rep_mint,
+        pay_mint: change.pay_mint,
+        effective_at,
+    });
 
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(
-    _court_name: String,
-    max_dispute_votes: u16
-)]
+#[instruction(_court_name: String)]
 pub struct EditCourt<'info> {
     #[account(
-        mut,
+        init,
+        seeds = ["court_change".as_bytes(), court.key().as_ref()],
+        bump,
+        payer = authority,
+        space = PendingCourtChange::SIZE
+    )]
+    pub court_change: Account<'info, PendingCourtChange>,
+    #[account(
         seeds = ["court".as_bytes(), _court_name.as_bytes()], bump = court.bump,
     )]
     pub court: Account<'info, Court>,
@@ -36,10 +77,7 @@ pub struct EditCourt<'info> {
         constraint = court.edit_authority == authority.key() @ InputError::InvalidEditAuthority
     )]
     pub authority: Signer<'info>,
-    ///CHECK: Old Authority must make sure this is correct, otherwise court is locked
-    pub transfer_authority: UncheckedAccount<'info>,
-    ///CHECK: New protocol that makes CPI has to sign for all init_disputes
-    pub transfer_protocol: UncheckedAccount<'info>,
-    pub rep_mint: Account<'info, Mint>,
-    pub pay_mint: Option<Account<'info, Mint>>,
-}
\ No newline at end of file
+    pub rep_mint: InterfaceAccount<'info, Mint>,
+    pub pay_mint: Option<InterfaceAccount<'info, Mint>>,
+    pub system_program: Program<'info, System>,
+}
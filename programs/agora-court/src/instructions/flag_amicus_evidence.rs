@@ -0,0 +1,74 @@
+use crate::{error::InputError, state::*};
+use anchor_lang::prelude::*;
+
+//a juror that revealed their vote flags an amicus submission as spam, once per submission
+//only during the reveal period - settle_amicus_bond compares the flags to the revealed votes,
+//so only revealed jurors may add to them
+
+pub fn flag_amicus_evidence(ctx: Context<FlagAmicusEvidence>, _court_name: String, dispute_id: u64) -> Result<()> {
+    let dispute = &ctx.accounts.dispute;
+    let juror = ctx.accounts.juror.key();
+    require!(dispute.party_index(juror).is_none(), InputError::AmicusFlagNotJuror);
+
+    let revealed = ctx.accounts.voter_record.claim_queue.iter()
+        .any(|x| x.dispute_id == dispute_id && matches!(x.user_voted_for, Vote::Reveal { .. }));
+    require!(revealed, InputError::AmicusFlagNotJuror);
+
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+    require!(
+        timestamp < dispute.config.dispute_ends_at && dispute.status == DisputeStatus::Reveal,
+        InputError::AmicusFlaggingClosed
+    );
+
+    let bump = *ctx.bumps.get("flag").unwrap();
+    ctx.accounts.flag.set_inner(AmicusFlag { bump });
+
+    let submission = &mut ctx.accounts.submission;
+    submission.spam_flags = submission.spam_flags.saturating_add(1);
+    submission.open_flags = submission.open_flags.saturating_add(1);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String, _dispute_id: u64)]
+pub struct FlagAmicusEvidence<'info> {
+    #[account(
+        init,
+        seeds = ["amicus_flag".as_bytes(), submission.key().as_ref(), juror.key().as_ref()],
+        bump,
+        payer = juror,
+        space = AmicusFlag::SIZE
+    )]
+    pub flag: Account<'info, AmicusFlag>,
+
+    #[account(
+        mut,
+        seeds = ["amicus".as_bytes(), dispute.key().as_ref(), submission.evidence.submitter.as_ref()],
+        bump = submission.bump,
+    )]
+    pub submission: Account<'info, AmicusSubmission>,
+
+    #[account(
+        seeds = ["record".as_bytes(), court.key().as_ref(), juror.key().as_ref()],
+        bump = voter_record.bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+
+    #[account(
+        seeds = ["dispute".as_bytes(), court.key().as_ref(), _dispute_id.to_be_bytes().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(mut)]
+    pub juror: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
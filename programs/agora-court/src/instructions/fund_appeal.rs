@@ -0,0 +1,105 @@
+use crate::{error::InputError, state::*};
+use anchor_lang::prelude::*;
+use crate::tools::{token::deposit, native::deposit_lamports};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+//any wallet can back a side of an appeal, contributions above what the side still needs are not taken
+//contributions are tracked per (contributor, side) so they can be settled in claim_appeal_contribution
+
+pub fn fund_appeal(
+    ctx: Context<FundAppeal>,
+    _court_name: String,
+    _dispute_id: u64,
+    side: u8,
+    amount: u64
+) -> Result<()> {
+    let accepted = ctx.accounts.appeal_fund.contribute(side as usize, amount)?;
+
+    if ctx.accounts.dispute.pays_native() {
+        deposit_lamports(
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.contributor.to_account_info(),
+            &ctx.accounts.appeal_fund.to_account_info(),
+            accepted
+        )?;
+    } else if let (Some(user_acc), Some(vault_acc), Some(mint_acc)) = (&ctx.accounts.contributor_pay_ata, &mut ctx.accounts.appeal_vault, &ctx.accounts.pay_mint) {
+        deposit(
+            &ctx.accounts.token_program.to_account_info(),
+            user_acc,
+            vault_acc,
+            mint_acc,
+            &ctx.accounts.contributor.to_account_info(),
+            accepted
+        )?;
+    } else {
+        return err!(InputError::PaymentAtaMissing);
+    }
+
+    let contribution = &mut ctx.accounts.contribution;
+    let bump = *ctx.bumps.get("contribution").unwrap();
+    contribution.contributor = ctx.accounts.contributor.key();
+    contribution.side = side;
+    contribution.amount = contribution.amount.checked_add(accepted).ok_or(InputError::StakeOverflow)?;
+    contribution.bump = bump;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String, _dispute_id: u64, side: u8)]
+pub struct FundAppeal<'info> {
+    #[account(
+        init_if_needed,
+        seeds = ["appeal_contribution".as_bytes(), appeal_fund.key().as_ref(), contributor.key().as_ref(), &[side]],
+        bump,
+        payer = contributor,
+        space = AppealContribution::SIZE
+    )]
+    pub contribution: Box<Account<'info, AppealContribution>>,
+
+    #[account(
+        mut,
+        seeds = ["appeal_fund".as_bytes(), dispute.key().as_ref()],
+        bump = appeal_fund.bump,
+    )]
+    pub appeal_fund: Box<Account<'info, AppealFund>>,
+
+    #[account(
+        mut,
+        seeds = ["appeal_vault".as_bytes(), dispute.key().as_ref()],
+        bump,
+        token::mint = pay_mint
+    )]
+    pub appeal_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        seeds = ["dispute".as_bytes(), court.key().as_ref(), _dispute_id.to_be_bytes().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    #[account(
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump
+    )]
+    pub court: Box<Account<'info, Court>>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = pay_mint,
+        token::authority = contributor
+    )]
+    pub contributor_pay_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        constraint = Some(pay_mint.key()) == dispute.pay_mint @ InputError::ProtocolMintMismatch
+    )]
+    pub pay_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,103 @@
+use crate::{error::InputError, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+//opens the appeal funding phase of a concluded dispute
+//anyone can then back a side through fund_appeal, and the appeal opens (as a new dispute, see
+//initialize_dispute) only if a side reaches the bond by funding_ends_at, and only until opening_ends_at.
+//the bond is raised in the court's pay mint - held in the appeal vault, or as lamports on the
+//fund itself for native SOL courts.
+
+pub fn initialize_appeal_fund(
+    ctx: Context<InitializeAppealFund>,
+    _court_name: String,
+    dispute_id: u64,
+    config: AppealConfiguration
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+    require!(timestamp < config.funding_ends_at && config.bond > 0, InputError::InvalidAppealConfig);
+    require!(config.funding_ends_at < config.opening_ends_at, InputError::InvalidAppealConfig);
+
+    let dispute = &ctx.accounts.dispute;
+    require!(matches!(dispute.status, DisputeStatus::Concluded { .. }), InputError::AppealRequiresConcludedDispute);
+
+    let court = &ctx.accounts.court;
+    require!(dispute.pay_mint.is_some(), InputError::AppealRequiresPayMint);
+    require!(dispute.pays_native() || ctx.accounts.appeal_vault.is_some(), InputError::PaymentAtaMissing);
+
+    let target_court = if config.escalate {
+        court.parent.ok_or(InputError::CourtHasNoParent)?
+    } else {
+        court.key()
+    };
+
+    let parties = dispute.users.clone();
+    let len = parties.len();
+
+    let appeal_fund = &mut ctx.accounts.appeal_fund;
+    let bump = *ctx.bumps.get("appeal_fund").unwrap();
+    appeal_fund.set_inner(AppealFund {
+        court: court.key(),
+        target_court,
+        dispute_id,
+        parties,
+        funded: vec![0; len],
+        config,
+        status: AppealStatus::Funding,
+        bump,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String, dispute_id: u64)]
+pub struct InitializeAppealFund<'info> {
+    #[account(
+        init,
+        seeds = ["appeal_fund".as_bytes(), dispute.key().as_ref()],
+        bump,
+        payer = payer,
+        space = AppealFund::get_size(&dispute.users)
+    )]
+    pub appeal_fund: Box<Account<'info, AppealFund>>,
+
+    #[account(
+        init,
+        seeds = ["appeal_vault".as_bytes(), dispute.key().as_ref()],
+        bump,
+        payer = payer,
+        token::mint = pay_mint,
+        token::authority = appeal_fund
+    )]
+    pub appeal_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        seeds = ["dispute".as_bytes(), court.key().as_ref(), dispute_id.to_be_bytes().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    #[account(
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump
+    )]
+    pub court: Box<Account<'info, Court>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        constraint = protocol.key() == dispute.protocol @ InputError::InvalidProtocol
+    )]
+    pub protocol: Signer<'info>, // protocol that opened the dispute decides whether and on what terms it is appealable
+
+    #[account(
+        constraint = Some(pay_mint.key()) == dispute.pay_mint @ InputError::ProtocolMintMismatch
+    )]
+    pub pay_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
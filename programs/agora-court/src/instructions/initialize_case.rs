@@ -1,4 +1,4 @@
-use crate::{error::InputError, state::{dispute::*, case::*, voter_record::*, Court}};
+use crate::{error::InputError, state::{dispute::*, case::*, voter_record::*, Court, CourtStatus}, tools::records::open_disputes};
 use anchor_lang::prelude::*;
 
 //when first case is added, change dispute status also
@@ -8,20 +8,26 @@ use anchor_lang::prelude::*;
 //in our prev implementation, the binary heap made perfect sense. It was to limit a user's exposure to disputes.
 //but now, with pre-dispute staking, that should be done in the interact section? TBD.
 
-pub fn initialize_case(ctx: Context<InitializeCase>, _court_name: String, dispute_id: u64, evidence: String) -> Result<()> {
+pub fn initialize_case(ctx: Context<InitializeCase>, _court_name: String, dispute_id: u64, evidence: EvidenceInput) -> Result<()> {
     let dispute = &mut ctx.accounts.dispute;
     let voter_record = &mut ctx.accounts.voter_record;
 
+    //ended disputes passed as remaining accounts that are still open don't block
+    let open = open_disputes(voter_record, &ctx.accounts.court.key(), ctx.remaining_accounts, ctx.program_id)?;
+    require!(!voter_record.has_unclaimed_disputes(&open), InputError::UserHasUnclaimedDisputes);
+
     //check timing / status
     dispute.can_add_case()?;
 
-    //initialize case account
+    //initialize case account with its first evidence item, more can follow through add_evidence
+    evidence.validate()?;
     let case = &mut ctx.accounts.case;
     let bump = *ctx.bumps.get("case").unwrap();
     case.set_inner(Case {
-        evidence,
+        evidence: Vec::new(),
         bump,
     });
+    case.push(evidence, ctx.accounts.payer.key());
 
     //push dispute to binary heap
     let dispute_record = DisputeRecord {
@@ -29,7 +35,9 @@ pub fn initialize_case(ctx: Context<InitializeCase>, _court_name: String, disput
         dispute_end_time: dispute.config.dispute_ends_at,
         user_voted_for: Vote::Reveal { key: ctx.accounts.payer.key() } //your own "case" - prevents voting for yourself
     };
+    let was_live = !voter_record.claim_queue.is_empty();
     voter_record.push(dispute_record);
+    ctx.accounts.court.track_record(was_live, true);
 
     //inc cases
     //See interact.rs WARNING comment. same issue here if there is a None in the vector
@@ -38,6 +46,12 @@ pub fn initialize_case(ctx: Context<InitializeCase>, _court_name: String, disput
     //then it's impossible for Voting to commence until time period starts.
 
     dispute.submitted_cases += 1;
+    if let Some(idx) = dispute.party_index(ctx.accounts.payer.key()) {
+        dispute.cases[idx] = true;
+    }
+    let cost = dispute.party_cost(ctx.accounts.payer.key()).unwrap_or_default();
+    dispute.case_stakes.rep = dispute.case_stakes.rep.checked_add(cost.rep).ok_or(InputError::StakeOverflow)?;
+    dispute.case_stakes.pay = dispute.case_stakes.pay.checked_add(cost.pay).ok_or(InputError::StakeOverflow)?;
     let none_count = dispute.users.iter().filter(|&&x| x.is_none()).count();
     if dispute.submitted_cases == (dispute.users.len() - none_count) as u8 {
         dispute.status = DisputeStatus::Voting;
@@ -47,7 +61,7 @@ pub fn initialize_case(ctx: Context<InitializeCase>, _court_name: String, disput
 }
 
 #[derive(Accounts)]
-#[instruction(_court_name: String, dispute_id: u64, evidence: String)]
+#[instruction(_court_name: String, dispute_id: u64, evidence: EvidenceInput)]
 pub struct InitializeCase<'info> {
     //a single user's case
     #[account(
@@ -55,7 +69,7 @@ pub struct InitializeCase<'info> {
         seeds = ["case".as_bytes(), dispute.key().as_ref(), payer.key().as_ref()],
         bump,
         payer = payer,
-        space = Case::get_size(&evidence)
+        space = Case::get_size(&[]) + EvidenceItem::get_size(&evidence)
     )]
     pub case: Account<'info, Case>,
 
@@ -65,8 +79,6 @@ pub struct InitializeCase<'info> {
         mut,
         seeds = ["record".as_bytes(), court.key().as_ref(), payer.key().as_ref()],
         bump = voter_record.bump,
-        constraint = !voter_record.has_unclaimed_disputes()
-                    @ InputError::UserHasUnclaimedDisputes,
         constraint = voter_record.claim_queue.len() < court.max_dispute_votes as usize
                     @ InputError::UserMaxDisputesReached,
         constraint = !voter_record.in_dispute(dispute_id) @ InputError::UserAlreadyProvidedCase
@@ -84,8 +96,10 @@ pub struct InitializeCase<'info> {
     pub dispute: Account<'info, Dispute>,
 
     #[account(
+        mut,
         seeds = ["court".as_bytes(), _court_name.as_bytes()],
         bump = court.bump,
+        constraint = court.status == CourtStatus::Active @ InputError::CourtNotActive
     )]
     pub court: Account<'info, Court>,
     
@@ -1,15 +1,17 @@
 use crate::{state::*, error::InputError};
 use anchor_lang::prelude::*;
-use anchor_spl::token::Mint;
+use anchor_spl::token_interface::Mint;
 
 //First IX a protocol would invoke
 
 pub fn initialize_court(
     ctx: Context<InitializeCourt>,
     _court_name: String,
-    max_dispute_votes: u16
+    max_dispute_votes: u16,
+    unbonding_period: i64
 ) -> Result<()> {
     require!(max_dispute_votes <= 200, InputError::MaxDisputeTooHigh);
+    require!(unbonding_period >= 0, InputError::InvalidUnbondingPeriod);
 
     let court = &mut ctx.accounts.court;
     let bump = *ctx.bumps.get("court").unwrap();
@@ -26,6 +28,20 @@ pub fn initialize_court(
         pay_mint,
         num_disputes: 0,
         max_dispute_votes,
+        live_records: 0,
+        unbonding_period,
+        crank_tip: 0,
+        soulbound: None,
+        bounds: None,
+        pending_authority: None,
+        pending_protocol: None,
+        change_delay: 0,
+        status: CourtStatus::Active,
+        parent: None,
+        juror_allowlist: false,
+        juror_collection: None,
+        policy_version: 0,
+        amicus: None,
         bump
     });
 
@@ -51,7 +67,7 @@ pub struct InitializeCourt<'info> {
     pub authority: Signer<'info>, //edit authority signs
     ///CHECK: protocol that makes CPI has to sign for all init_disputes
     pub protocol: UncheckedAccount<'info>,
-    pub rep_mint: Account<'info, Mint>,
-    pub pay_mint: Option<Account<'info, Mint>>,
+    pub rep_mint: InterfaceAccount<'info, Mint>,
+    pub pay_mint: Option<InterfaceAccount<'info, Mint>>,
     pub system_program: Program<'info, System>,
 }
@@ -1,6 +1,7 @@
-use crate::{error::InputError, state::dispute::*, state::court::Court};
+use crate::{error::InputError, state::dispute::*, state::{court::{Court, CourtStatus}, appeal_fund::*, protocol_entry::ProtocolEntry, dispute_template::*}};
 use anchor_lang::prelude::*;
-use anchor_spl::{token::{Mint, TokenAccount, transfer, Token}, associated_token::AssociatedToken};
+use crate::tools::{token::{deposit, withdraw, init_vault}, native::{deposit_lamports, withdraw_lamports}};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 //change initialize dispute to create the vault with the money pool (both tokens)
 //based on option of pay mint or rep mint, also have Option for accounts passed in (ata)
@@ -18,16 +19,102 @@ pub fn initialize_dispute(
     ctx: Context<InitializeDispute>,
     _court_name: String,
     users: Vec<Option<Pubkey>>,
-    config: DisputeConfiguration
+    config: Option<DisputeConfiguration>,
+    party_costs: Vec<PartyCost>,
+    overrides: Option<DisputeOverrides>
 ) -> Result<()> {
-    //check end times
     let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    //court.protocol is always allowed, other protocols need an entry (see add_protocol)
+    let protocol = ctx.accounts.protocol.key();
+    require!(protocol == ctx.accounts.court.protocol || ctx.accounts.protocol_entry.is_some(), InputError::InvalidProtocol);
+
+    //config comes from exactly one of: the explicit arg, a court template (+ overrides), or the protocol's defaults
+    let config = match (config, &ctx.accounts.dispute_template) {
+        (Some(_), Some(_)) => return err!(InputError::TemplateConflict),
+        (Some(x), None) => x,
+        (None, Some(template)) => {
+            require!(template.court == ctx.accounts.court.key(), InputError::TemplateCourtMismatch);
+            template.apply(&overrides.unwrap_or_default())?.to_config(timestamp)?
+        },
+        (None, None) => match &ctx.accounts.protocol_entry {
+            Some(entry) => entry.defaults.as_ref().ok_or(InputError::ProtocolDefaultsMissing)?.to_config(timestamp)?,
+            None => return err!(InputError::ProtocolDefaultsMissing),
+        },
+    };
+    if let Some(entry) = &mut ctx.accounts.protocol_entry {
+        entry.record_dispute()?;
+    }
+
+    //check end times
     require!(timestamp < config.grace_ends_at, InputError::InvalidEndTime);
     require!(config.grace_ends_at < config.init_cases_ends_at, InputError::InvalidEndTime);
     require!(config.init_cases_ends_at < config.voting_ends_at, InputError::InvalidEndTime);
     require!(config.voting_ends_at < config.dispute_ends_at, InputError::InvalidEndTime);
     require!(!users.is_empty(), InputError::UsersEmpty);
 
+    //party_index resolves a user to one slot, so a user may only hold one
+    for (i, user) in users.iter().enumerate() {
+        if user.is_some() {
+            require!(!users[i + 1..].contains(user), InputError::DuplicateUser);
+        }
+    }
+
+    //per-slot stakes, e.g. a claimant posting a larger bond than the respondent
+    //empty => every slot pays the config defaults
+    require!(party_costs.is_empty() || party_costs.len() == users.len(), InputError::PartyCostsLengthMismatch);
+    let custom_costs = !party_costs.is_empty();
+    let mut party_costs = if party_costs.is_empty() {
+        vec![PartyCost { rep: config.rep_cost, pay: config.pay_cost }; users.len()]
+    } else {
+        party_costs
+    };
+
+    if let Some(bounds) = &ctx.accounts.court.bounds {
+        bounds.check(&config, timestamp, users.len(), &party_costs)?;
+    }
+    if let Some(template) = &ctx.accounts.dispute_template {
+        template.policy.check(&config, timestamp, &users, &party_costs, custom_costs)?;
+    }
+
+    //soulbound rep is frozen in user accounts, so it can only gate voting - never be staked
+    if ctx.accounts.court.soulbound.is_some() {
+        require!(
+            party_costs.iter().all(|x| x.rep == 0) && config.voter_rep_cost == 0 && config.protocol_rep == 0,
+            InputError::SoulboundReputationNotTransferable
+        );
+    }
+
+    //an appeal runs as a fresh dispute between the same sides, once its fund has been raised
+    //escalated appeals from a sub-court are opened in its parent
+    //each fully funded side's bond stands in for that side's own pay stake - it is moved onto this
+    //dispute below, paid to voters if the side loses and returned to its contributors otherwise
+    let mut appeal_bond = 0;
+    if let Some(appeal_fund) = &mut ctx.accounts.appeal_fund {
+        require!(appeal_fund.target_court == ctx.accounts.court.key(), InputError::AppealDisputeMismatch);
+        appeal_fund.can_open(&users)?;
+
+        let appealed = ctx.accounts.appealed_dispute.as_ref().ok_or(InputError::AppealDisputeMismatch)?;
+        let expected = Pubkey::create_program_address(
+            &[
+                "appeal_fund".as_bytes(),
+                appealed.key().as_ref(),
+                &[appeal_fund.bump]
+            ],
+            ctx.program_id
+        ).map_err(|_| InputError::AppealDisputeMismatch)?;
+        require!(appeal_fund.key() == expected, InputError::AppealDisputeMismatch);
+        require!(appealed.pay_mint == ctx.accounts.court.pay_mint, InputError::AppealMintMismatch);
+
+        for (cost, &funded) in party_costs.iter_mut().zip(appeal_fund.funded.iter()) {
+            if funded >= appeal_fund.config.bond {
+                cost.pay = 0;
+            }
+        }
+        appeal_bond = appeal_fund.config.bond;
+        appeal_fund.status = AppealStatus::Appealed { dispute_id: ctx.accounts.court.num_disputes };
+    }
+
     let provided_rep = config.protocol_rep;
     let provided_pay = config.protocol_pay;
     let len = users.len();
@@ -38,63 +125,159 @@ pub fn initialize_dispute(
     dispute.set_inner(Dispute {
         users,
         votes: vec![0; len],
+        committed_votes: 0,
         status: DisputeStatus::Grace,
         interactions: 0,
         submitted_cases: 0,
         config,
+        party_costs,
+        cases: vec![false; len],
+        appeal_bonds: vec![0; len],
+        case_stakes: PartyCost::default(),
+        protocol,
+        rep_mint: ctx.accounts.court.rep_mint,
+        pay_mint: ctx.accounts.court.pay_mint,
+        soulbound: ctx.accounts.court.soulbound.clone(),
+        policy_version: ctx.accounts.court.policy_version,
         bump,
     });
     ctx.accounts.court.num_disputes += 1;
 
+    //each vault is created under its own mint's token program, so a court can mix spl-token and Token-2022
+    let dispute_key = ctx.accounts.dispute.key();
+    let rep_vault_bump = *ctx.bumps.get("rep_vault").unwrap();
+    let mut rep_vault = init_vault(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.rep_vault.to_account_info(),
+        &ctx.accounts.rep_mint,
+        &ctx.accounts.dispute.to_account_info(),
+        &[&["rep_vault".as_bytes(), dispute_key.as_ref(), &[rep_vault_bump]]],
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.rep_token_program.to_account_info()
+    )?;
+
+    let mut pay_vault = match (&ctx.accounts.pay_vault, &ctx.accounts.pay_mint) {
+        (Some(vault), Some(mint)) => {
+            require!(Some(mint.key()) == ctx.accounts.court.pay_mint && !ctx.accounts.court.pays_native(), InputError::ProtocolMintMismatch);
+            let pay_vault_bump = *ctx.bumps.get("pay_vault").unwrap();
+
+            Some(init_vault(
+                &ctx.accounts.payer.to_account_info(),
+                &vault.to_account_info(),
+                mint,
+                &ctx.accounts.dispute.to_account_info(),
+                &[&["pay_vault".as_bytes(), dispute_key.as_ref(), &[pay_vault_bump]]],
+                &ctx.accounts.system_program.to_account_info(),
+                &ctx.accounts.pay_token_program.to_account_info()
+            )?)
+        },
+        (Some(_), None) => return err!(InputError::PaymentAtaMissing),
+        (None, _) => None,
+    };
+
     //transfer rep tokens from the actual protocol
     if provided_rep > 0 {
         let protocol_ata = &mut ctx.accounts.protocol_rep_ata;
 
         if let Some(acc) = protocol_ata {
-            let cpi_ctx = CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                anchor_spl::token::Transfer {
-                    from: acc.to_account_info(),
-                    to: ctx.accounts.rep_vault.to_account_info(),
-                    authority: ctx.accounts.protocol.to_account_info(),
-                }
-            );
-    
-            transfer(cpi_ctx, provided_rep)?;
+            deposit(
+                &ctx.accounts.rep_token_program.to_account_info(),
+                acc,
+                &mut rep_vault,
+                &ctx.accounts.rep_mint,
+                &ctx.accounts.protocol.to_account_info(),
+                provided_rep
+            )?;
         } else {
             return err!(InputError::ReputationAtaMissing);
         }
     }
 
-    //transfer pay tokens from actual protocol
-    if provided_pay > 0 {
+    //transfer pay lamports from actual protocol straight onto the dispute
+    if provided_pay > 0 && ctx.accounts.court.pays_native() {
+        deposit_lamports(
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.protocol.to_account_info(),
+            &ctx.accounts.dispute.to_account_info(),
+            provided_pay
+        )?;
+    } else if provided_pay > 0 {
+        //transfer pay tokens from actual protocol
         let protocol_ata = &mut ctx.accounts.protocol_pay_ata;
-        let vault_ata = &mut ctx.accounts.pay_vault;
+        let vault_ata = &mut pay_vault;
 
         if let (Some(protocol_acc), Some(vault_acc), Some(mint), Some(mint_acc)) = (protocol_ata, vault_ata, &ctx.accounts.court.pay_mint, &ctx.accounts.pay_mint) {
             require!(mint_acc.key() == *mint, InputError::ProtocolMintMismatch);
-            
-            let cpi_ctx = CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                anchor_spl::token::Transfer {
-                    from: protocol_acc.to_account_info(),
-                    to: vault_acc.to_account_info(),
-                    authority: ctx.accounts.protocol.to_account_info()
-                }
-            );
-
-            transfer(cpi_ctx, provided_pay)?;
+
+            deposit(
+                &ctx.accounts.pay_token_program.to_account_info(),
+                protocol_acc,
+                vault_acc,
+                mint_acc,
+                &ctx.accounts.protocol.to_account_info(),
+                provided_pay
+            )?;
         } else {
             return err!(InputError::PaymentAtaMissing);
         }
     }
 
+    //move each fully funded side's bond from the appeal fund, recording what actually arrived for that side
+    if appeal_bond > 0 {
+        let appeal_fund = ctx.accounts.appeal_fund.as_ref().ok_or(InputError::AppealDisputeMismatch)?;
+
+        for side in 0..len {
+            if !appeal_fund.spent(side) {
+                continue;
+            }
+
+            let received = if ctx.accounts.court.pays_native() {
+                withdraw_lamports(
+                    &appeal_fund.to_account_info(),
+                    &ctx.accounts.dispute.to_account_info(),
+                    appeal_bond
+                )?;
+                appeal_bond
+            } else if let (Some(appeal_vault), Some(vault_acc), Some(mint_acc), Some(appealed)) = (&ctx.accounts.appeal_vault, &mut pay_vault, &ctx.accounts.pay_mint, &ctx.accounts.appealed_dispute) {
+                require!(Some(mint_acc.key()) == ctx.accounts.court.pay_mint, InputError::ProtocolMintMismatch);
+
+                let appealed_key = appealed.key();
+                let signer_seeds: &[&[&[u8]]] = &[
+                    &[
+                        "appeal_fund".as_bytes(),
+                        appealed_key.as_ref(),
+                        &[appeal_fund.bump]
+                    ]
+                ];
+                let before = vault_acc.amount;
+
+                withdraw(
+                    &ctx.accounts.pay_token_program.to_account_info(),
+                    appeal_vault,
+                    vault_acc,
+                    mint_acc,
+                    &appeal_fund.to_account_info(),
+                    signer_seeds,
+                    appeal_bond
+                )?;
+
+                vault_acc.reload()?;
+                vault_acc.amount.checked_sub(before).ok_or(InputError::TransferAmountMismatch)?
+            } else {
+                return err!(InputError::PaymentAtaMissing);
+            };
+
+            ctx.accounts.dispute.appeal_bonds[side] = received;
+        }
+    }
+
     Ok(())
+
 }
 
 
 #[derive(Accounts)]
-#[instruction(_court_name: String, users: Vec<Option<Pubkey>>, config: DisputeConfiguration)]
+#[instruction(_court_name: String, users: Vec<Option<Pubkey>>)]
 pub struct InitializeDispute<'info> {
     #[account(
         init,
@@ -106,61 +289,80 @@ pub struct InitializeDispute<'info> {
     pub dispute: Box<Account<'info, Dispute>>,
 
     #[account(
-        init_if_needed,
-        payer = payer,
-        associated_token::mint = rep_mint,
-        associated_token::authority = dispute
+        mut,
+        seeds = ["rep_vault".as_bytes(), dispute.key().as_ref()],
+        bump
     )]
-    pub rep_vault: Box<Account<'info, TokenAccount>>,
+    ///CHECK: created in the handler under rep_token_program
+    pub rep_vault: UncheckedAccount<'info>,
 
     #[account(
-        init_if_needed,
-        payer = payer,
-        associated_token::mint = pay_mint,
-        associated_token::authority = dispute
+        mut,
+        seeds = ["pay_vault".as_bytes(), dispute.key().as_ref()],
+        bump
     )]
-    pub pay_vault: Option<Account<'info, TokenAccount>>,
+    ///CHECK: created in the handler under pay_token_program
+    pub pay_vault: Option<UncheckedAccount<'info>>,
 
     #[account(
         mut,
         seeds = ["court".as_bytes(), _court_name.as_bytes()],
-        bump = court.bump
+        bump = court.bump,
+        constraint = court.status == CourtStatus::Active @ InputError::CourtNotActive
     )]
     pub court: Box<Account<'info, Court>>,
 
     #[account(mut)]
     pub payer: Signer<'info>, //payer may be the same as protocol, but not when PDA
 
+    #[account(mut)]
+    pub protocol: Signer<'info>, // protocol that makes CPI needs to sign again - court.protocol or registered
+
     #[account(
         mut,
-        constraint = protocol.key() == court.protocol @ InputError::InvalidProtocol
+        seeds = ["protocol".as_bytes(), court.key().as_ref(), protocol.key().as_ref()],
+        bump = protocol_entry.bump,
     )]
-    pub protocol: Signer<'info>, // protocol that makes CPI needs to sign again
+    pub protocol_entry: Option<Box<Account<'info, ProtocolEntry>>>,
+
+    pub dispute_template: Option<Box<Account<'info, DisputeTemplate>>>, // court checked in the handler
 
     #[account(
         mut,
-        associated_token::mint = rep_mint,
-        associated_token::authority = protocol
+        token::mint = rep_mint,
+        token::authority = protocol
     )]
-    pub protocol_rep_ata: Option<Account<'info, TokenAccount>>,
+    pub protocol_rep_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
         mut,
-        associated_token::mint = pay_mint,
-        associated_token::authority = protocol
+        token::mint = pay_mint,
+        token::authority = protocol
     )]
-    pub protocol_pay_ata: Option<Account<'info, TokenAccount>>,
+    pub protocol_pay_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
         constraint = rep_mint.key() == court.rep_mint @ InputError::ReputationMintMismatch
     )]
-    pub rep_mint: Box<Account<'info, Mint>>,
+    pub rep_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub pay_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
 
-    pub pay_mint: Option<Account<'info, Mint>>,
+    #[account(mut)]
+    pub appeal_fund: Option<Box<Account<'info, AppealFund>>>, // only when opening a crowdfunded appeal
+
+    pub appealed_dispute: Option<Box<Account<'info, Dispute>>>, // the dispute being appealed, checked against appeal_fund in the handler
+
+    #[account(
+        mut,
+        token::mint = pay_mint,
+        token::authority = appeal_fund
+    )]
+    pub appeal_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     pub system_program: Program<'info, System>,
 
-    pub token_program: Program<'info, Token>,
+    pub rep_token_program: Interface<'info, TokenInterface>,
 
-    pub associated_token_program: Program<'info, AssociatedToken>
+    pub pay_token_program: Interface<'info, TokenInterface>, // same as rep_token_program when the court has no pay mint
 } 
\ No newline at end of file
@@ -1,5 +1,5 @@
 //use std::collections::BinaryHeap;
-use crate::state::{VoterRecord, Court};
+use crate::state::{VoterRecord, JurorStake, Court};
 use anchor_lang::prelude::*;
 
 pub fn initialize_record(ctx: Context<InitializeRecord>, _court_name: String) -> Result<()> {
@@ -9,6 +9,8 @@ pub fn initialize_record(ctx: Context<InitializeRecord>, _court_name: String) ->
         claim_queue: Vec::new(),
         currently_staked_rep: 0,
         currently_staked_pay: 0,
+        juror_stake: JurorStake::default(),
+        rep_debt: 0,
         bump,
     });
     Ok(())
@@ -0,0 +1,32 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+//creates the global court registry, once per deployment - anyone can pay for it
+
+pub fn initialize_registry(ctx: Context<InitializeRegistry>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let bump = *ctx.bumps.get("registry").unwrap();
+    registry.set_inner(CourtRegistry {
+        num_courts: 0,
+        bump,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeRegistry<'info> {
+    #[account(
+        init,
+        seeds = ["registry".as_bytes()],
+        bump,
+        payer = payer,
+        space = CourtRegistry::SIZE
+    )]
+    pub registry: Account<'info, CourtRegistry>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,89 @@
+use crate::{state::*, error::InputError};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+
+//Alternative to initialize_court - the court creates and owns its rep mint.
+//Court PDA is both mint and freeze authority, so rep can only be earned by voting
+//coherently and any account the court touches is left frozen (non-transferable).
+
+pub fn initialize_soulbound_court(
+    ctx: Context<InitializeSoulboundCourt>,
+    _court_name: String,
+    max_dispute_votes: u16,
+    decimals: u8,
+    coherent_reward: u64,
+    incoherent_slash: u64,
+) -> Result<()> {
+    require!(max_dispute_votes <= 200, InputError::MaxDisputeTooHigh);
+
+    let court = &mut ctx.accounts.court;
+    let bump = *ctx.bumps.get("court").unwrap();
+    msg!("soulbound rep mint: {}, decimals: {}", ctx.accounts.rep_mint.key(), decimals);
+
+    let pay_mint = ctx.accounts.pay_mint.as_ref().map(|n| n.key());
+
+    court.set_inner(Court {
+        edit_authority: ctx.accounts.authority.key(),
+        protocol: ctx.accounts.protocol.key(),
+        rep_mint: ctx.accounts.rep_mint.key(),
+        pay_mint,
+        num_disputes: 0,
+        max_dispute_votes,
+        live_records: 0,
+        unbonding_period: 0, //soulbound rep never leaves user accounts, so there is no juror stake to unbond
+        crank_tip: 0,
+        soulbound: Some(SoulboundConfig {
+            coherent_reward,
+            incoherent_slash,
+        }),
+        bounds: None,
+        pending_authority: None,
+        pending_protocol: None,
+        change_delay: 0,
+        status: CourtStatus::Active,
+        parent: None,
+        juror_allowlist: false,
+        juror_collection: None,
+        policy_version: 0,
+        amicus: None,
+        bump
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(
+    _court_name: String,
+    max_dispute_votes: u16,
+    decimals: u8
+)]
+pub struct InitializeSoulboundCourt<'info> {
+    #[account(
+        init,
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump,
+        payer = authority,
+        space = Court::SIZE
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(
+        init,
+        seeds = ["rep_mint".as_bytes(), court.key().as_ref()],
+        bump,
+        payer = authority,
+        mint::decimals = decimals,
+        mint::authority = court,
+        mint::freeze_authority = court
+    )]
+    pub rep_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>, //edit authority signs
+    ///CHECK: protocol that makes CPI has to sign for all init_disputes
+    pub protocol: UncheckedAccount<'info>,
+    pub pay_mint: Option<Account<'info, Mint>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
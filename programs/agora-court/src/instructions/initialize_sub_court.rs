@@ -0,0 +1,82 @@
+use crate::{state::*, error::InputError};
+use anchor_lang::prelude::*;
+
+//creates a court under an existing one, e.g. "marketplace/digital-goods" under "marketplace"
+//the child shares the parent's mints and has its own settings, bounds, templates and protocols.
+//its jurors also count as eligible in the parent (see select_vote) and appeals can escalate to the parent.
+//soulbound parents are excluded - only the parent PDA can mint their rep.
+
+pub fn initialize_sub_court(
+    ctx: Context<InitializeSubCourt>,
+    parent_name: String,
+    court_name: String,
+    max_dispute_votes: u16,
+    unbonding_period: i64
+) -> Result<()> {
+    require!(max_dispute_votes <= 200, InputError::MaxDisputeTooHigh);
+    require!(unbonding_period >= 0, InputError::InvalidUnbondingPeriod);
+
+    let prefix = format!("{}/", parent_name);
+    require!(court_name.len() > prefix.len() && court_name.starts_with(&prefix), InputError::SubCourtNameInvalid);
+
+    let parent = &ctx.accounts.parent;
+    require!(parent.soulbound.is_none(), InputError::SubCourtOfSoulbound);
+
+    let court = &mut ctx.accounts.court;
+    let bump = *ctx.bumps.get("court").unwrap();
+
+    court.set_inner(Court {
+        edit_authority: ctx.accounts.authority.key(),
+        protocol: ctx.accounts.protocol.key(),
+        rep_mint: parent.rep_mint,
+        pay_mint: parent.pay_mint,
+        num_disputes: 0,
+        max_dispute_votes,
+        live_records: 0,
+        unbonding_period,
+        crank_tip: 0,
+        soulbound: None,
+        bounds: None,
+        pending_authority: None,
+        pending_protocol: None,
+        change_delay: 0,
+        status: CourtStatus::Active,
+        parent: Some(parent.key()),
+        juror_allowlist: false,
+        juror_collection: None,
+        policy_version: 0,
+        amicus: None,
+        bump
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(parent_name: String, court_name: String)]
+pub struct InitializeSubCourt<'info> {
+    #[account(
+        init,
+        seeds = ["court".as_bytes(), court_name.as_bytes()],
+        bump,
+        payer = authority,
+        space = Court::SIZE
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(
+        seeds = ["court".as_bytes(), parent_name.as_bytes()],
+        bump = parent.bump,
+        constraint = parent.status == CourtStatus::Active @ InputError::CourtNotActive
+    )]
+    pub parent: Account<'info, Court>,
+
+    #[account(
+        mut,
+        constraint = parent.edit_authority == authority.key() @ InputError::InvalidEditAuthority
+    )]
+    pub authority: Signer<'info>, //parent's edit authority, also edits the child
+    ///CHECK: protocol that makes CPI has to sign for all init_disputes
+    pub protocol: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
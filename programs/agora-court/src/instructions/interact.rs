@@ -1,6 +1,7 @@
-use crate::{error::InputError, state::dispute::*, state::{court::Court, voter_record::VoterRecord}};
+use crate::{error::InputError, state::dispute::*, state::{court::{Court, CourtStatus}, voter_record::VoterRecord}};
 use anchor_lang::prelude::*;
-use anchor_spl::{token::{Mint, TokenAccount, transfer, Token, Transfer}, associated_token::AssociatedToken};
+use crate::tools::{token::deposit, native::deposit_lamports};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 //when a user interacts, lock up their funds for a set amount of time as specified by the protocol
 //this is a CPI from the actual protocol
@@ -57,50 +58,55 @@ pub fn interact(
         users[i] = Some(*signer);
     }
 
-    //transfer correct money to vault pool
-    let provided_rep = ctx.accounts.dispute.config.rep_cost;
-    let provided_pay = ctx.accounts.dispute.config.pay_cost;
+    //transfer correct money to vault pool - each slot has its own stake
+    let provided_rep = ctx.accounts.dispute.party_costs[i].rep;
+    let provided_pay = ctx.accounts.dispute.party_costs[i].pay;
 
     //transfer rep tokens from user -> vault
     if provided_rep > 0 {
         let user_ata = &mut ctx.accounts.user_rep_ata;
 
         if let Some(acc) = user_ata {
-            let cpi_ctx = CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: acc.to_account_info(),
-                    to: ctx.accounts.rep_vault.to_account_info(),
-                    authority: ctx.accounts.user.to_account_info()
-                }
-            );
-
-            transfer(cpi_ctx, provided_rep)?;
-            ctx.accounts.record.currently_staked_rep += provided_rep;
+            deposit(
+                &ctx.accounts.rep_token_program.to_account_info(),
+                acc,
+                &mut ctx.accounts.rep_vault,
+                &ctx.accounts.rep_mint,
+                &ctx.accounts.user.to_account_info(),
+                provided_rep
+            )?;
+            ctx.accounts.record.stake(provided_rep, 0)?;
         } else {
             return err!(InputError::ReputationAtaMissing);
         }
     }
 
-    //transfer pay tokens from user -> vault
-    if provided_pay > 0 {
+    //transfer pay lamports from user -> dispute
+    if provided_pay > 0 && ctx.accounts.dispute.pays_native() {
+        deposit_lamports(
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.user.to_account_info(),
+            &ctx.accounts.dispute.to_account_info(),
+            provided_pay
+        )?;
+        ctx.accounts.record.stake(0, provided_pay)?;
+    } else if provided_pay > 0 {
+        //transfer pay tokens from user -> vault
         let user_ata = &mut ctx.accounts.user_pay_ata;
         let vault_ata = &mut ctx.accounts.pay_vault;
 
-        if let (Some(user_acc), Some(vault_acc), Some(mint), Some(mint_acc)) = (user_ata, vault_ata, ctx.accounts.court.pay_mint, &ctx.accounts.pay_mint) {
+        if let (Some(user_acc), Some(vault_acc), Some(mint), Some(mint_acc)) = (user_ata, vault_ata, ctx.accounts.dispute.pay_mint, &ctx.accounts.pay_mint) {
             require!(mint_acc.key() == mint, InputError::ProtocolMintMismatch);
 
-            let cpi_ctx = CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: user_acc.to_account_info(),
-                    to: vault_acc.to_account_info(),
-                    authority: ctx.accounts.user.to_account_info()
-                }
-            );
-
-            transfer(cpi_ctx, provided_pay)?;
-            ctx.accounts.record.currently_staked_pay += provided_pay;
+            deposit(
+                &ctx.accounts.pay_token_program.to_account_info(),
+                user_acc,
+                vault_acc,
+                mint_acc,
+                &ctx.accounts.user.to_account_info(),
+                provided_pay
+            )?;
+            ctx.accounts.record.stake(0, provided_pay)?;
         } else {
             return err!(InputError::PaymentAtaMissing);
         }
@@ -138,17 +144,19 @@ pub struct Interact<'info> {
 
     #[account(
         mut,
-        associated_token::mint = rep_mint,
-        associated_token::authority = dispute
+        seeds = ["rep_vault".as_bytes(), dispute.key().as_ref()],
+        bump,
+        token::mint = rep_mint
     )]
-    pub rep_vault: Box<Account<'info, TokenAccount>>,
+    pub rep_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
-        associated_token::mint = pay_mint,
-        associated_token::authority = dispute
+        seeds = ["pay_vault".as_bytes(), dispute.key().as_ref()],
+        bump,
+        token::mint = pay_mint
     )]
-    pub pay_vault: Option<Account<'info, TokenAccount>>,
+    pub pay_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
         mut,
@@ -160,6 +168,7 @@ pub struct Interact<'info> {
     #[account(
         seeds = ["court".as_bytes(), _court_name.as_bytes()],
         bump = court.bump,
+        constraint = court.status == CourtStatus::Active @ InputError::CourtNotActive
     )]
     pub court: Box<Account<'info, Court>>,
 
@@ -167,28 +176,28 @@ pub struct Interact<'info> {
     pub user: Signer<'info>,
     #[account(
         mut,
-        associated_token::mint = rep_mint,
-        associated_token::authority = user,
+        token::mint = rep_mint,
+        token::authority = user,
     )]
-    pub user_rep_ata: Option<Account<'info, TokenAccount>>,
+    pub user_rep_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
         mut,
-        associated_token::mint = pay_mint,
-        associated_token::authority = user
+        token::mint = pay_mint,
+        token::authority = user
     )]
-    pub user_pay_ata: Option<Account<'info, TokenAccount>>,
+    pub user_pay_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
-        constraint = rep_mint.key() == court.rep_mint @ InputError::ReputationMintMismatch
+        constraint = rep_mint.key() == dispute.rep_mint @ InputError::ReputationMintMismatch
     )]
-    pub rep_mint: Account<'info, Mint>,
+    pub rep_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    pub pay_mint: Option<Account<'info, Mint>>,
+    pub pay_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
 
     pub system_program: Program<'info, System>,
 
-    pub token_program: Program<'info, Token>,
+    pub rep_token_program: Interface<'info, TokenInterface>,
 
-    pub associated_token_program: Program<'info, AssociatedToken>
+    pub pay_token_program: Interface<'info, TokenInterface>, // same as rep_token_program when the court has no pay mint
 } 
\ No newline at end of file
@@ -4,15 +4,48 @@ pub mod initialize_dispute;
 pub use initialize_case::*;
 pub mod initialize_case;
 
+pub use add_evidence::*;
+pub mod add_evidence;
+
+pub use set_amicus_config::*;
+pub mod set_amicus_config;
+
+pub use submit_amicus_evidence::*;
+pub mod submit_amicus_evidence;
+
+pub use flag_amicus_evidence::*;
+pub mod flag_amicus_evidence;
+
+pub use settle_amicus_bond::*;
+pub mod settle_amicus_bond;
+
 pub use initialize_court::*;
 pub mod initialize_court;
 
+pub use initialize_sub_court::*;
+pub mod initialize_sub_court;
+
+pub use initialize_soulbound_court::*;
+pub mod initialize_soulbound_court;
+
 pub use initialize_record::*;
 pub mod initialize_record;
 
+pub use resize_record::*;
+pub mod resize_record;
+
+pub use close_record::*;
+pub mod close_record;
+
 pub use claim::*;
 pub mod claim;
 
+pub use claim_many::*;
+pub mod claim_many;
+
+pub use crank_claim::*;
+pub mod crank_claim;
+
 pub use close_dispute::*;
 pub mod close_dispute;
 
@@ -25,5 +58,86 @@ pub mod select_vote;
 pub use edit_court::*;
 pub mod edit_court;
 
+pub use apply_court_change::*;
+pub mod apply_court_change;
+
+pub use cancel_court_change::*;
+pub mod cancel_court_change;
+
+pub use set_paused::*;
+pub mod set_paused;
+
+pub use wind_down::*;
+pub mod wind_down;
+
+pub use wind_down_dispute::*;
+pub mod wind_down_dispute;
+
+pub use set_juror_allowlist::*;
+pub mod set_juror_allowlist;
+
+pub use add_juror::*;
+pub mod add_juror;
+
+pub use remove_juror::*;
+pub mod remove_juror;
+
+pub use set_juror_collection::*;
+pub mod set_juror_collection;
+
+pub use set_court_metadata::*;
+pub mod set_court_metadata;
+
+pub use initialize_registry::*;
+pub mod initialize_registry;
+
+pub use register_court::*;
+pub mod register_court;
+
+pub use add_protocol::*;
+pub mod add_protocol;
+
+pub use remove_protocol::*;
+pub mod remove_protocol;
+
+pub use set_dispute_template::*;
+pub mod set_dispute_template;
+
+pub use set_config_bounds::*;
+pub mod set_config_bounds;
+
+pub use propose_transfer::*;
+pub mod propose_transfer;
+
+pub use accept_authority::*;
+pub mod accept_authority;
+
+pub use accept_protocol::*;
+pub mod accept_protocol;
+
+pub use cancel_transfer::*;
+pub mod cancel_transfer;
+
 pub use reveal_vote::*;
 pub mod reveal_vote;
+
+pub use initialize_appeal_fund::*;
+pub mod initialize_appeal_fund;
+
+pub use fund_appeal::*;
+pub mod fund_appeal;
+
+pub use claim_appeal_contribution::*;
+pub mod claim_appeal_contribution;
+
+pub use deposit_stake::*;
+pub mod deposit_stake;
+
+pub use request_unstake::*;
+pub mod request_unstake;
+
+pub use withdraw_stake::*;
+pub mod withdraw_stake;
+
+pub use refund_protocol::*;
+pub mod refund_protocol;
@@ -0,0 +1,39 @@
+use crate::{error::InputError, state::*};
+use anchor_lang::prelude::*;
+
+//first step of handing the court over - nothing changes until the new key signs accept_authority / accept_protocol
+//None leaves that side's proposal as it is, proposing again replaces it
+
+pub fn propose_transfer(
+    ctx: Context<ProposeTransfer>,
+    _court_name: String,
+    authority: Option<Pubkey>,
+    protocol: Option<Pubkey>
+) -> Result<()> {
+    let court = &mut ctx.accounts.court;
+
+    if authority.is_some() {
+        court.pending_authority = authority;
+    }
+    if protocol.is_some() {
+        court.pending_protocol = protocol;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct ProposeTransfer<'info> {
+    #[account(
+        mut,
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(
+        constraint = court.edit_authority == authority.key() @ InputError::InvalidEditAuthority
+    )]
+    pub authority: Signer<'info>,
+}
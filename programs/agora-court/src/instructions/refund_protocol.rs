@@ -0,0 +1,138 @@
+use crate::{error::InputError, state::*, tools::{token::withdraw, native::withdraw_lamports}};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+//returns protocol_rep / protocol_pay to the protocol when a dispute concludes without a winner
+//(no cases, a tie, or wind_down_dispute) - nobody is rewarded, so the incentive would otherwise sit in the vaults.
+//the config amounts are zeroed once refunded, so this runs at most once per dispute.
+
+pub fn refund_protocol(ctx: Context<RefundProtocol>, _court_name: String, dispute_id: u64) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    require!(
+        dispute.status == DisputeStatus::Concluded { winner: None },
+        InputError::ProtocolRefundUnavailable
+    );
+
+    let rep_amount = dispute.config.protocol_rep;
+    let pay_amount = dispute.config.protocol_pay;
+    require!(rep_amount > 0 || pay_amount > 0, InputError::ProtocolRefundUnavailable);
+    dispute.config.protocol_rep = 0;
+    dispute.config.protocol_pay = 0;
+
+    msg!("rep to refund: {}, pay to refund: {}", rep_amount, pay_amount);
+
+    let court_key = ctx.accounts.court.key();
+    let id_bytes = dispute_id.to_be_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[
+        &[
+            "dispute".as_bytes(),
+            court_key.as_ref(),
+            id_bytes.as_ref(),
+            &[dispute.bump]
+        ]
+    ];
+
+    if rep_amount > 0 {
+        if let Some(acc) = &ctx.accounts.protocol_rep_ata {
+            withdraw(
+                &ctx.accounts.rep_token_program.to_account_info(),
+                &ctx.accounts.rep_vault,
+                acc,
+                &ctx.accounts.rep_mint,
+                &dispute.to_account_info(),
+                signer_seeds,
+                rep_amount
+            )?;
+        } else {
+            return err!(InputError::ReputationAtaMissing);
+        }
+    }
+
+    if pay_amount > 0 && dispute.pays_native() {
+        withdraw_lamports(
+            &dispute.to_account_info(),
+            &ctx.accounts.protocol.to_account_info(),
+            pay_amount
+        )?;
+    } else if pay_amount > 0 {
+        if let (Some(protocol_acc), Some(vault_acc), Some(mint), Some(mint_acc)) = (&ctx.accounts.protocol_pay_ata, &ctx.accounts.pay_vault, &dispute.pay_mint, &ctx.accounts.pay_mint) {
+            require!(mint_acc.key() == *mint, InputError::ProtocolMintMismatch);
+
+            withdraw(
+                &ctx.accounts.pay_token_program.to_account_info(),
+                vault_acc,
+                protocol_acc,
+                mint_acc,
+                &dispute.to_account_info(),
+                signer_seeds,
+                pay_amount
+            )?;
+        } else {
+            return err!(InputError::PaymentAtaMissing);
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String, dispute_id: u64)]
+pub struct RefundProtocol<'info> {
+    #[account(
+        mut,
+        seeds = ["dispute".as_bytes(), court.key().as_ref(), dispute_id.to_be_bytes().as_ref()],
+        bump = dispute.bump,
+        constraint = dispute.protocol == protocol.key() @ InputError::InvalidProtocol,
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    #[account(
+        mut,
+        seeds = ["rep_vault".as_bytes(), dispute.key().as_ref()],
+        bump,
+        token::mint = rep_mint
+    )]
+    pub rep_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = ["pay_vault".as_bytes(), dispute.key().as_ref()],
+        bump,
+        token::mint = pay_mint
+    )]
+    pub pay_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Box<Account<'info, Court>>,
+
+    #[account(mut)]
+    pub protocol: Signer<'info>, // the protocol that opened the dispute
+
+    #[account(
+        mut,
+        token::mint = rep_mint,
+        token::authority = protocol
+    )]
+    pub protocol_rep_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = pay_mint,
+        token::authority = protocol
+    )]
+    pub protocol_pay_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        constraint = rep_mint.key() == dispute.rep_mint @ InputError::ReputationMintMismatch
+    )]
+    pub rep_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub pay_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    pub rep_token_program: Interface<'info, TokenInterface>,
+
+    pub pay_token_program: Interface<'info, TokenInterface>, // same as rep_token_program when the court has no pay mint
+}
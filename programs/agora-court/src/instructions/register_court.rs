@@ -0,0 +1,66 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+//adds a court to the global registry so clients can discover it without knowing its name
+//permissionless - the court seeds prove it exists, the payer covers the new entry
+
+pub fn register_court(ctx: Context<RegisterCourt>, court_name: String) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let index = registry.num_courts;
+    registry.num_courts += 1;
+
+    let bump = *ctx.bumps.get("entry").unwrap();
+    ctx.accounts.entry.set_inner(RegistryEntry {
+        court: ctx.accounts.court.key(),
+        court_name,
+        bump,
+    });
+
+    let bump = *ctx.bumps.get("listing").unwrap();
+    ctx.accounts.listing.set_inner(RegistryListing {
+        index,
+        bump,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(court_name: String)]
+pub struct RegisterCourt<'info> {
+    #[account(
+        mut,
+        seeds = ["registry".as_bytes()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, CourtRegistry>,
+
+    #[account(
+        init,
+        seeds = ["registry".as_bytes(), registry.num_courts.to_be_bytes().as_ref()],
+        bump,
+        payer = payer,
+        space = RegistryEntry::SIZE
+    )]
+    pub entry: Account<'info, RegistryEntry>,
+
+    #[account(
+        init,
+        seeds = ["registry_listing".as_bytes(), court.key().as_ref()],
+        bump,
+        payer = payer,
+        space = RegistryListing::SIZE
+    )]
+    pub listing: Account<'info, RegistryListing>,
+
+    #[account(
+        seeds = ["court".as_bytes(), court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,32 @@
+use crate::{error::InputError, state::*};
+use anchor_lang::prelude::*;
+
+//revokes a juror's pass - blocks future votes only, committed votes can still be revealed and claimed
+
+pub fn remove_juror(_ctx: Context<RemoveJuror>, _court_name: String) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct RemoveJuror<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = ["juror".as_bytes(), court.key().as_ref(), juror_pass.juror.as_ref()],
+        bump = juror_pass.bump,
+    )]
+    pub juror_pass: Account<'info, JurorPass>,
+
+    #[account(
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(
+        mut,
+        constraint = court.edit_authority == authority.key() @ InputError::InvalidEditAuthority
+    )]
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,33 @@
+use crate::{error::InputError, state::*};
+use anchor_lang::prelude::*;
+
+//revokes a protocol's entry, returning its rent to the edit authority
+//disputes it already opened keep running
+
+pub fn remove_protocol(_ctx: Context<RemoveProtocol>, _court_name: String) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct RemoveProtocol<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = ["protocol".as_bytes(), court.key().as_ref(), protocol_entry.protocol.as_ref()],
+        bump = protocol_entry.bump,
+    )]
+    pub protocol_entry: Account<'info, ProtocolEntry>,
+
+    #[account(
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(
+        mut,
+        constraint = court.edit_authority == authority.key() @ InputError::InvalidEditAuthority
+    )]
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,29 @@
+use crate::state::{voter_record::VoterRecord, Court};
+use anchor_lang::prelude::*;
+
+//moves free stake into unbonding - it stops counting towards eligibility right away,
+//but can only be withdrawn once the court's unbonding period has passed
+
+pub fn request_unstake(ctx: Context<RequestUnstake>, _court_name: String, amount: u64) -> Result<()> {
+    let unbonding_period = ctx.accounts.court.unbonding_period;
+    ctx.accounts.record.juror_stake.request_unstake(amount, unbonding_period)
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct RequestUnstake<'info> {
+    #[account(
+        mut,
+        seeds = ["record".as_bytes(), court.key().as_ref(), user.key().as_ref()],
+        bump = record.bump,
+    )]
+    pub record: Account<'info, VoterRecord>,
+
+    #[account(
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    pub user: Signer<'info>,
+}
@@ -0,0 +1,37 @@
+use crate::{error::InputError, state::{VoterRecord, Court}};
+use anchor_lang::prelude::*;
+
+//records are sized for court.max_dispute_votes when initialized
+//after edit_court changes the limit, the owner resizes their record to match - paying for growth,
+//getting rent back on shrink. never shrinks below what the claim queue currently holds.
+
+pub fn resize_record(_ctx: Context<ResizeRecord>, _court_name: String) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct ResizeRecord<'info> {
+    #[account(
+        mut,
+        seeds = ["record".as_bytes(), court.key().as_ref(), payer.key().as_ref()],
+        bump = record.bump,
+        constraint = record.claim_queue.len() <= court.max_dispute_votes as usize
+                    @ InputError::RecordQueueTooLong,
+        realloc = VoterRecord::get_size(court.max_dispute_votes),
+        realloc::payer = payer,
+        realloc::zero = false,
+    )]
+    pub record: Account<'info, VoterRecord>,
+
+    #[account(
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>, // record owner
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,7 +1,6 @@
-use crate::{error::InputError, state::{dispute::*, voter_record::*, Court}};
+use crate::{error::InputError, state::{dispute::*, voter_record::*, Court, CourtStatus, JurorPass}, tools::{records::open_disputes, metadata::in_verified_collection}};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, Mint, TokenAccount, self};
-use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount};
 
 pub fn select_vote(
     ctx: Context<SelectVote>, 
@@ -9,12 +8,48 @@ pub fn select_vote(
     dispute_id: u64,
     commitment: [u8; 32]
 ) -> Result<()> {
+    let child_stake = match (&ctx.accounts.child_court, &ctx.accounts.child_record) {
+        (Some(child_court), Some(child_record)) => {
+            require!(child_court.parent == Some(ctx.accounts.court.key()), InputError::ChildRecordMismatch);
+            //either court can swap its rep mint on its own - child stake only counts while both use the same one
+            require!(child_court.rep_mint == ctx.accounts.court.rep_mint, InputError::ReputationMintMismatch);
+            let (child_key, payer_key) = (child_court.key(), ctx.accounts.payer.key());
+            let seeds: &[&[u8]] = &["record".as_bytes(), child_key.as_ref(), payer_key.as_ref(), &[child_record.bump]];
+            let expected = Pubkey::create_program_address(seeds, ctx.program_id).map_err(|_| InputError::ChildRecordMismatch)?;
+            require!(child_record.key() == expected, InputError::ChildRecordMismatch);
+            child_record.juror_stake.free
+        },
+        (None, None) => 0,
+        _ => return err!(InputError::ChildRecordMismatch),
+    };
+
+    if ctx.accounts.court.juror_allowlist {
+        require!(ctx.accounts.juror_pass.is_some(), InputError::JurorNotAllowed);
+    }
+
+    //collection-gated courts - the NFT either stands in for voter_rep_required or is needed on top of it
+    let rep_waived = match (&ctx.accounts.court.juror_collection, &ctx.accounts.nft_ata, &ctx.accounts.nft_metadata) {
+        (Some(gate), Some(ata), Some(metadata)) => {
+            require!(ata.amount > 0, InputError::NotInJurorCollection);
+            require!(in_verified_collection(metadata, &ata.mint, &gate.collection)?, InputError::NotInJurorCollection);
+            gate.replaces_rep
+        },
+        (Some(_), _, _) => return err!(InputError::NotInJurorCollection),
+        (None, _, _) => false,
+    };
+
     let dispute = &mut ctx.accounts.dispute;
     let voter_record = &mut ctx.accounts.voter_record;
-    let user_ata = &mut ctx.accounts.user_rep_ata;
+
+    //ended disputes passed as remaining accounts that are still open don't block
+    let open = open_disputes(voter_record, &ctx.accounts.court.key(), ctx.remaining_accounts, ctx.program_id)?;
+    require!(!voter_record.has_unclaimed_disputes(&open), InputError::UserHasUnclaimedDisputes);
 
     //check timing / status
     dispute.can_vote()?;
+
+    //juror stake sits in the court's current rep mint, it can't back a dispute created under an older one
+    require!(dispute.rep_mint == ctx.accounts.court.rep_mint, InputError::ReputationMintMismatch);
     msg!("Dispute ID: {}", dispute_id);
 
     //already voted, update vote and return
@@ -25,29 +60,25 @@ pub fn select_vote(
         dispute_end_time: dispute.config.dispute_ends_at,
         user_voted_for: Vote::Secret { hash: commitment }
     };
+    let was_live = !voter_record.claim_queue.is_empty();
     voter_record.push(dispute_record);
+    ctx.accounts.court.track_record(was_live, true);
 
-    //ensure user balance is sufficient
-    let true_balance = voter_record.currently_staked_rep + user_ata.amount;
-    if true_balance < dispute.config.voter_rep_required {
+    //ensure user balance is sufficient - soulbound rep can't be staked, so it's checked in the wallet,
+    //less any slashes crank_claim has left unburned
+    //free stake in a sub-court record counts too, but the rep cost is only ever locked here
+    let balance = match (&dispute.soulbound, &ctx.accounts.user_rep_ata) {
+        (Some(_), Some(acc)) => acc.amount.saturating_sub(voter_record.rep_debt),
+        (Some(_), None) => return err!(InputError::ReputationAtaMissing),
+        (None, _) => voter_record.juror_stake.free.saturating_add(child_stake),
+    };
+    if !rep_waived && balance < dispute.config.voter_rep_required {
         return err!(InputError::UserDoesNotHaveEnoughReputation);
     }
 
-    //transfer rep cost
-    let rep_cost = dispute.config.voter_rep_cost;
-    if rep_cost > 0 {
-        let cpi_ctx = CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            anchor_spl::token::Transfer {
-                from: user_ata.to_account_info(),
-                to: ctx.accounts.rep_vault.to_account_info(),
-                authority: ctx.accounts.payer.to_account_info(),
-            }
-        );
-
-        token::transfer(cpi_ctx, rep_cost)?;
-        voter_record.currently_staked_rep += rep_cost;
-    }
+    //lock rep cost in the court stake vault until claim
+    voter_record.juror_stake.lock(dispute.config.voter_rep_cost)?;
+    dispute.committed_votes = dispute.committed_votes.checked_add(1).ok_or(InputError::StakeOverflow)?;
 
     Ok(())
 }
@@ -63,8 +94,6 @@ pub struct SelectVote<'info> {
         mut,
         seeds = ["record".as_bytes(), court.key().as_ref(), payer.key().as_ref()],
         bump = voter_record.bump,
-        constraint = !voter_record.has_unclaimed_disputes()
-                    @ InputError::UserHasUnclaimedDisputes,
         constraint = voter_record.claim_queue.len() < court.max_dispute_votes as usize
                     @ InputError::UserMaxDisputesReached,
         constraint = !voter_record.in_dispute(dispute_id) @ InputError::UserAlreadyProvidedCase
@@ -80,32 +109,40 @@ pub struct SelectVote<'info> {
 
     #[account(
         mut,
-        associated_token::mint = rep_mint,
-        associated_token::authority = dispute
-    )]
-    pub rep_vault: Account<'info, TokenAccount>,
-
-    #[account(
         seeds = ["court".as_bytes(), _court_name.as_bytes()],
         bump = court.bump,
+        constraint = court.status == CourtStatus::Active @ InputError::CourtNotActive
     )]
     pub court: Box<Account<'info, Court>>,
 
     #[account(
-        constraint = rep_mint.key() == court.rep_mint @ InputError::ReputationMintMismatch
+        constraint = rep_mint.key() == dispute.rep_mint @ InputError::ReputationMintMismatch
     )]
-    pub rep_mint: Box<Account<'info, Mint>>,
+    pub rep_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(mut)]
     pub payer: Signer<'info>, // user voting
 
     #[account(
-        mut,
-        associated_token::mint = rep_mint,
-        associated_token::authority = payer,
+        token::mint = rep_mint,
+        token::authority = payer,
+    )]
+    pub user_rep_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>, // soulbound courts only
+
+    pub child_court: Option<Box<Account<'info, Court>>>, // sub-court whose record adds to the voter's eligibility
+    pub child_record: Option<Box<Account<'info, VoterRecord>>>, // checked against child_court in the handler
+
+    #[account(
+        seeds = ["juror".as_bytes(), court.key().as_ref(), payer.key().as_ref()],
+        bump = juror_pass.bump,
+    )]
+    pub juror_pass: Option<Box<Account<'info, JurorPass>>>, // allow-listed courts only
+
+    #[account(
+        token::authority = payer,
     )]
-    pub user_rep_ata: Account<'info, TokenAccount>,
+    pub nft_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>, // collection-gated courts only
+    ///CHECK: metaplex metadata of nft_ata's mint, address and owner checked in tools/metadata.rs
+    pub nft_metadata: Option<UncheckedAccount<'info>>,
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>
 }
\ No newline at end of file
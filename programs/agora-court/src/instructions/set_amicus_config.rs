@@ -0,0 +1,38 @@
+use crate::{error::InputError, state::*};
+use anchor_lang::prelude::*;
+
+//enables third-party (amicus) evidence with the given bond, None disables it
+//submissions already made keep the bond and threshold they were made under
+
+pub fn set_amicus_config(
+    ctx: Context<SetAmicusConfig>,
+    _court_name: String,
+    config: Option<AmicusConfig>
+) -> Result<()> {
+    if let Some(x) = &config {
+        require!(x.spam_threshold_bps > 0 && x.spam_threshold_bps as u128 <= AmicusSubmission::BPS, InputError::InvalidAmicusConfig);
+
+        //a forfeited bond must be able to open an empty treasury on its own
+        require!(x.bond == 0 || x.bond >= Rent::get()?.minimum_balance(0), InputError::AmicusBondBelowRent);
+    }
+
+    ctx.accounts.court.amicus = config;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct SetAmicusConfig<'info> {
+    #[account(
+        mut,
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(
+        constraint = court.edit_authority == authority.key() @ InputError::InvalidEditAuthority
+    )]
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,35 @@
+use crate::{error::InputError, state::*};
+use anchor_lang::prelude::*;
+
+//sets the limits initialize_dispute checks every dispute config against, None lifts them
+//existing disputes are not re-checked
+
+pub fn set_config_bounds(
+    ctx: Context<SetConfigBounds>,
+    _court_name: String,
+    bounds: Option<ConfigBounds>
+) -> Result<()> {
+    if let Some(x) = &bounds {
+        require!(x.is_valid(), InputError::InvalidConfigBounds);
+    }
+
+    ctx.accounts.court.bounds = bounds;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct SetConfigBounds<'info> {
+    #[account(
+        mut,
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(
+        constraint = court.edit_authority == authority.key() @ InputError::InvalidEditAuthority
+    )]
+    pub authority: Signer<'info>,
+}
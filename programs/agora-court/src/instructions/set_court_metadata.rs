@@ -0,0 +1,73 @@
+use crate::{error::InputError, events::CourtPolicyUpdated, state::*};
+use anchor_lang::prelude::*;
+
+//creates or updates the court's metadata
+//a new policy hash bumps court.policy_version - disputes created from then on record the new version
+
+pub fn set_court_metadata(
+    ctx: Context<SetCourtMetadata>,
+    _court_name: String,
+    display_name: String,
+    policy_uri: String,
+    policy_hash: [u8; 32]
+) -> Result<()> {
+    require!(display_name.len() <= MAX_DISPLAY_NAME_LEN, InputError::MetadataTooLong);
+    require!(policy_uri.len() <= MAX_POLICY_URI_LEN, InputError::MetadataTooLong);
+
+    let court = &mut ctx.accounts.court;
+    let metadata = &mut ctx.accounts.metadata;
+
+    let policy_changed = court.policy_version == 0 || metadata.policy_hash != policy_hash;
+    if policy_changed {
+        court.policy_version = court.policy_version.checked_add(1).ok_or(InputError::PolicyVersionOverflow)?;
+    }
+
+    let bump = *ctx.bumps.get("metadata").unwrap();
+    metadata.set_inner(CourtMetadata {
+        court: court.key(),
+        display_name,
+        policy_uri,
+        policy_hash,
+        policy_version: court.policy_version,
+        bump,
+    });
+
+    if policy_changed {
+        emit!(CourtPolicyUpdated {
+            court: court.key(),
+            policy_uri: metadata.policy_uri.clone(),
+            policy_hash,
+            policy_version: court.policy_version,
+        });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct SetCourtMetadata<'info> {
+    #[account(
+        init_if_needed,
+        seeds = ["court_metadata".as_bytes(), court.key().as_ref()],
+        bump,
+        payer = authority,
+        space = CourtMetadata::SIZE
+    )]
+    pub metadata: Account<'info, CourtMetadata>,
+
+    #[account(
+        mut,
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(
+        mut,
+        constraint = court.edit_authority == authority.key() @ InputError::InvalidEditAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,55 @@
+use crate::{error::InputError, state::*};
+use anchor_lang::prelude::*;
+
+//creates or replaces a named dispute template, see initialize_dispute
+//disputes already created from it keep their resolved config
+
+pub fn set_dispute_template(
+    ctx: Context<SetDisputeTemplate>,
+    _court_name: String,
+    _template_name: String,
+    defaults: DisputeDefaults,
+    overridable: u16,
+    policy: TemplatePolicy
+) -> Result<()> {
+    require!(policy.is_valid(), InputError::InvalidConfigBounds);
+
+    let template = &mut ctx.accounts.template;
+    let bump = *ctx.bumps.get("template").unwrap();
+    template.set_inner(DisputeTemplate {
+        court: ctx.accounts.court.key(),
+        defaults,
+        overridable,
+        policy,
+        bump,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String, _template_name: String)]
+pub struct SetDisputeTemplate<'info> {
+    #[account(
+        init_if_needed,
+        seeds = ["template".as_bytes(), court.key().as_ref(), _template_name.as_bytes()],
+        bump,
+        payer = authority,
+        space = DisputeTemplate::SIZE
+    )]
+    pub template: Account<'info, DisputeTemplate>,
+
+    #[account(
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(
+        mut,
+        constraint = court.edit_authority == authority.key() @ InputError::InvalidEditAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,25 @@
+use crate::{error::InputError, state::*};
+use anchor_lang::prelude::*;
+
+//turns the juror allow-list on or off - while on, select_vote needs the voter's JurorPass (see add_juror)
+
+pub fn set_juror_allowlist(ctx: Context<SetJurorAllowlist>, _court_name: String, enabled: bool) -> Result<()> {
+    ctx.accounts.court.juror_allowlist = enabled;
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct SetJurorAllowlist<'info> {
+    #[account(
+        mut,
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(
+        constraint = court.edit_authority == authority.key() @ InputError::InvalidEditAuthority
+    )]
+    pub authority: Signer<'info>,
+}
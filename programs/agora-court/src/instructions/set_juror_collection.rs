@@ -0,0 +1,29 @@
+use crate::{error::InputError, state::*};
+use anchor_lang::prelude::*;
+
+//sets or clears the NFT collection jurors must hold to vote, see select_vote
+
+pub fn set_juror_collection(
+    ctx: Context<SetJurorCollection>,
+    _court_name: String,
+    gate: Option<CollectionGate>
+) -> Result<()> {
+    ctx.accounts.court.juror_collection = gate;
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct SetJurorCollection<'info> {
+    #[account(
+        mut,
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(
+        constraint = court.edit_authority == authority.key() @ InputError::InvalidEditAuthority
+    )]
+    pub authority: Signer<'info>,
+}
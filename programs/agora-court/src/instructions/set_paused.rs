@@ -0,0 +1,30 @@
+use crate::{error::InputError, state::*};
+use anchor_lang::prelude::*;
+
+//emergency stop - takes effect immediately, unlike edit_court
+//new disputes, interactions, cases and votes are rejected, running disputes can still reveal, close and claim
+
+pub fn set_paused(ctx: Context<SetPaused>, _court_name: String, paused: bool) -> Result<()> {
+    let court = &mut ctx.accounts.court;
+    require!(court.status != CourtStatus::WindingDown, InputError::CourtWindingDown);
+
+    court.status = if paused { CourtStatus::Paused } else { CourtStatus::Active };
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct SetPaused<'info> {
+    #[account(
+        mut,
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(
+        constraint = court.edit_authority == authority.key() @ InputError::InvalidEditAuthority
+    )]
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,84 @@
+use crate::{error::InputError, state::*, tools::native::withdraw_lamports};
+use anchor_lang::{prelude::*, AccountsClose};
+
+//permissionless, once the dispute has concluded - closes the submission to its submitter.
+//spam (see AmicusSubmission::is_spam) forfeits the bond to the court treasury first, which funds crank tips.
+//remaining accounts hold [flag, juror] pairs, each flag is closed back to the juror that paid for it.
+//the bond is only settled once every flag is closed, so heavily flagged submissions can settle over several calls.
+
+pub fn settle_amicus_bond<'info>(
+    ctx: Context<'_, '_, '_, 'info, SettleAmicusBond<'info>>,
+    _court_name: String,
+    _dispute_id: u64
+) -> Result<()> {
+    let dispute = &ctx.accounts.dispute;
+    require!(matches!(dispute.status, DisputeStatus::Concluded { .. }), InputError::DisputeNotClaimable);
+
+    let submission_key = ctx.accounts.submission.key();
+    let pairs = ctx.remaining_accounts.chunks_exact(2);
+    require!(pairs.remainder().is_empty(), InputError::AmicusFlagMismatch);
+
+    for pair in pairs {
+        let (flag_info, juror) = (&pair[0], &pair[1]);
+        let flag: Account<'info, AmicusFlag> = Account::try_from(flag_info)?;
+
+        let seeds: &[&[u8]] = &["amicus_flag".as_bytes(), submission_key.as_ref(), juror.key.as_ref(), &[flag.bump]];
+        let expected = Pubkey::create_program_address(seeds, ctx.program_id).map_err(|_| InputError::AmicusFlagMismatch)?;
+        require!(flag_info.key() == expected, InputError::AmicusFlagMismatch);
+
+        flag.close(juror.clone())?;
+        ctx.accounts.submission.open_flags = ctx.accounts.submission.open_flags.saturating_sub(1);
+    }
+
+    let submission = &ctx.accounts.submission;
+    if submission.open_flags > 0 {
+        msg!("flags left to close: {}", submission.open_flags);
+        return Ok(());
+    }
+
+    let spam = submission.is_spam(dispute.total_votes());
+    if spam && submission.bond > 0 {
+        withdraw_lamports(
+            &submission.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            submission.bond
+        )?;
+    }
+    msg!("spam flags: {}, bond forfeited: {}", submission.spam_flags, spam);
+
+    submission.close(ctx.accounts.submitter.to_account_info())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String, _dispute_id: u64)]
+pub struct SettleAmicusBond<'info> {
+    #[account(
+        mut,
+        seeds = ["amicus".as_bytes(), dispute.key().as_ref(), submitter.key().as_ref()],
+        bump = submission.bump,
+    )]
+    pub submission: Account<'info, AmicusSubmission>, // closed to the submitter once its flags are
+
+    #[account(
+        seeds = ["dispute".as_bytes(), court.key().as_ref(), _dispute_id.to_be_bytes().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(
+        mut,
+        seeds = ["treasury".as_bytes(), court.key().as_ref()],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    ///CHECK: submission owner, only receives lamports - the submission seeds tie it to the submission
+    #[account(mut)]
+    pub submitter: UncheckedAccount<'info>,
+}
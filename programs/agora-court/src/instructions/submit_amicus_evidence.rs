@@ -0,0 +1,84 @@
+use crate::{error::InputError, state::*, tools::native::deposit_lamports};
+use anchor_lang::prelude::*;
+
+//lets any non-party attach evidence during the case period, posting court.amicus.bond in lamports
+//the bond comes back through settle_amicus_bond unless enough jurors flag the submission as spam
+
+pub fn submit_amicus_evidence(
+    ctx: Context<SubmitAmicusEvidence>,
+    _court_name: String,
+    _dispute_id: u64,
+    item: EvidenceInput
+) -> Result<()> {
+    item.validate()?;
+
+    let amicus = ctx.accounts.court.amicus.clone().ok_or(InputError::AmicusDisabled)?;
+
+    let dispute = &ctx.accounts.dispute;
+    let submitter = ctx.accounts.submitter.key();
+    require!(dispute.party_index(submitter).is_none(), InputError::AmicusIsParty);
+
+    dispute.can_add_evidence()?;
+
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let submission = &mut ctx.accounts.submission;
+    let bump = *ctx.bumps.get("submission").unwrap();
+    submission.set_inner(AmicusSubmission {
+        dispute: dispute.key(),
+        evidence: EvidenceItem {
+            uri: item.uri,
+            content_hash: item.content_hash,
+            mime_type: item.mime_type,
+            submitter,
+            submitted_at: timestamp,
+        },
+        bond: amicus.bond,
+        spam_threshold_bps: amicus.spam_threshold_bps,
+        spam_flags: 0,
+        open_flags: 0,
+        bump,
+    });
+
+    if amicus.bond > 0 {
+        deposit_lamports(
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.submitter.to_account_info(),
+            &ctx.accounts.submission.to_account_info(),
+            amicus.bond
+        )?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String, _dispute_id: u64, item: EvidenceInput)]
+pub struct SubmitAmicusEvidence<'info> {
+    #[account(
+        init,
+        seeds = ["amicus".as_bytes(), dispute.key().as_ref(), submitter.key().as_ref()],
+        bump,
+        payer = submitter,
+        space = AmicusSubmission::get_size(&item)
+    )]
+    pub submission: Account<'info, AmicusSubmission>,
+
+    #[account(
+        seeds = ["dispute".as_bytes(), court.key().as_ref(), _dispute_id.to_be_bytes().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+        constraint = court.status == CourtStatus::Active @ InputError::CourtNotActive
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
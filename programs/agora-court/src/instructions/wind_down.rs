@@ -0,0 +1,26 @@
+use crate::{error::InputError, state::*};
+use anchor_lang::prelude::*;
+
+//puts the court into permanent wind-down - blocks new activity like a pause, and lets
+//wind_down_dispute conclude any open dispute without a winner so all stakes are refunded
+
+pub fn wind_down(ctx: Context<WindDown>, _court_name: String) -> Result<()> {
+    ctx.accounts.court.status = CourtStatus::WindingDown;
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct WindDown<'info> {
+    #[account(
+        mut,
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Account<'info, Court>,
+
+    #[account(
+        constraint = court.edit_authority == authority.key() @ InputError::InvalidEditAuthority
+    )]
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,27 @@
+use crate::{error::InputError, state::*};
+use anchor_lang::prelude::*;
+
+//permissionless - once the court is winding down, anyone can conclude an open dispute as winner: None
+//disputes aren't enumerable on chain, so keepers run this per dispute id up to court.num_disputes
+
+pub fn wind_down_dispute(ctx: Context<WindDownDispute>, _court_name: String, _dispute_id: u64) -> Result<()> {
+    ctx.accounts.dispute.wind_down()
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String, _dispute_id: u64)]
+pub struct WindDownDispute<'info> {
+    #[account(
+        mut,
+        seeds = ["dispute".as_bytes(), court.key().as_ref(), _dispute_id.to_be_bytes().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+        constraint = court.status == CourtStatus::WindingDown @ InputError::CourtNotWindingDown
+    )]
+    pub court: Account<'info, Court>,
+}
@@ -0,0 +1,70 @@
+use crate::{error::InputError, state::{voter_record::VoterRecord, Court}};
+use anchor_lang::prelude::*;
+use crate::tools::token::withdraw;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+//pays out everything that has finished unbonding
+
+pub fn withdraw_stake(ctx: Context<WithdrawStake>, court_name: String) -> Result<()> {
+    let amount = ctx.accounts.record.juror_stake.withdraw()?;
+
+    let court = &ctx.accounts.court;
+    let court_seeds: &[&[&[u8]]] = &[
+        &[
+            "court".as_bytes(),
+            court_name.as_bytes(),
+            &[court.bump]
+        ]
+    ];
+
+    withdraw(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.stake_vault,
+        &ctx.accounts.user_rep_ata,
+        &ctx.accounts.rep_mint,
+        &court.to_account_info(),
+        court_seeds,
+        amount
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(_court_name: String)]
+pub struct WithdrawStake<'info> {
+    #[account(
+        mut,
+        seeds = ["record".as_bytes(), court.key().as_ref(), user.key().as_ref()],
+        bump = record.bump,
+    )]
+    pub record: Box<Account<'info, VoterRecord>>,
+
+    #[account(
+        mut,
+        seeds = ["stake_vault".as_bytes(), court.key().as_ref()],
+        bump,
+        token::mint = rep_mint
+    )]
+    pub stake_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = ["court".as_bytes(), _court_name.as_bytes()],
+        bump = court.bump,
+    )]
+    pub court: Box<Account<'info, Court>>,
+
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = rep_mint,
+        token::authority = user,
+    )]
+    pub user_rep_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = rep_mint.key() == court.rep_mint @ InputError::ReputationMintMismatch
+    )]
+    pub rep_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
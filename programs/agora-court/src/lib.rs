@@ -3,6 +3,7 @@ use instructions::*;
 use state::*;
 
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod tools;
@@ -29,6 +30,22 @@ pub mod agora_court {
         instructions::claim(ctx, court_name, dispute_id)
     }
 
+    pub fn claim_many<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimMany<'info>>,
+        court_name: String,
+        dispute_ids: Vec<u64>,
+    ) -> Result<()> {
+        instructions::claim_many(ctx, court_name, dispute_ids)
+    }
+
+    pub fn crank_claim(
+        ctx: Context<CrankClaim>,
+        court_name: String,
+        dispute_id: u64,
+    ) -> Result<()> {
+        instructions::crank_claim(ctx, court_name, dispute_id)
+    }
+
     pub fn close_dispute(
         ctx: Context<CloseDispute>, 
         dispute_id: u64
@@ -40,33 +57,251 @@ pub mod agora_court {
         ctx: Context<InitializeCase>,
         court_name: String,
         dispute_id: u64, 
-        evidence: String) -> Result<()> {
+        evidence: EvidenceInput) -> Result<()> {
         instructions::initialize_case(ctx, court_name, dispute_id, evidence)
     }
 
+    pub fn add_evidence(
+        ctx: Context<AddEvidence>,
+        court_name: String,
+        dispute_id: u64,
+        item: EvidenceInput,
+    ) -> Result<()> {
+        instructions::add_evidence(ctx, court_name, dispute_id, item)
+    }
+
+    pub fn set_amicus_config(
+        ctx: Context<SetAmicusConfig>,
+        court_name: String,
+        config: Option<AmicusConfig>,
+    ) -> Result<()> {
+        instructions::set_amicus_config(ctx, court_name, config)
+    }
+
+    pub fn submit_amicus_evidence(
+        ctx: Context<SubmitAmicusEvidence>,
+        court_name: String,
+        dispute_id: u64,
+        item: EvidenceInput,
+    ) -> Result<()> {
+        instructions::submit_amicus_evidence(ctx, court_name, dispute_id, item)
+    }
+
+    pub fn flag_amicus_evidence(
+        ctx: Context<FlagAmicusEvidence>,
+        court_name: String,
+        dispute_id: u64,
+    ) -> Result<()> {
+        instructions::flag_amicus_evidence(ctx, court_name, dispute_id)
+    }
+
+    pub fn settle_amicus_bond<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleAmicusBond<'info>>,
+        court_name: String,
+        dispute_id: u64,
+    ) -> Result<()> {
+        instructions::settle_amicus_bond(ctx, court_name, dispute_id)
+    }
+
     pub fn initialize_court(
         ctx: Context<InitializeCourt>,
         court_name: String,
-        max_dispute_votes: u16
+        max_dispute_votes: u16,
+        unbonding_period: i64,
+    ) -> Result<()> {
+        instructions::initialize_court(ctx, court_name, max_dispute_votes, unbonding_period)
+    }
+
+    pub fn initialize_soulbound_court(
+        ctx: Context<InitializeSoulboundCourt>,
+        court_name: String,
+        max_dispute_votes: u16,
+        decimals: u8,
+        coherent_reward: u64,
+        incoherent_slash: u64
+    ) -> Result<()> {
+        instructions::initialize_soulbound_court(ctx, court_name, max_dispute_votes, decimals, coherent_reward, incoherent_slash)
+    }
+
+    pub fn initialize_sub_court(
+        ctx: Context<InitializeSubCourt>,
+        parent_name: String,
+        court_name: String,
+        max_dispute_votes: u16,
+        unbonding_period: i64,
     ) -> Result<()> {
-        instructions::initialize_court(ctx, court_name, max_dispute_votes)
+        instructions::initialize_sub_court(ctx, parent_name, court_name, max_dispute_votes, unbonding_period)
     }
 
     pub fn edit_court(
         ctx: Context<EditCourt>,
         court_name: String,
-        max_dispute_votes: u16
+        max_dispute_votes: u16,
+        unbonding_period: i64,
+        crank_tip: u64,
+        change_delay: i64,
     ) -> Result<()> {
-        instructions::edit_court(ctx, court_name, max_dispute_votes)
+        instructions::edit_court(ctx, court_name, max_dispute_votes, unbonding_period, crank_tip, change_delay)
+    }
+
+    pub fn apply_court_change(
+        ctx: Context<ApplyCourtChange>,
+        court_name: String,
+    ) -> Result<()> {
+        instructions::apply_court_change(ctx, court_name)
+    }
+
+    pub fn cancel_court_change(
+        ctx: Context<CancelCourtChange>,
+        court_name: String,
+    ) -> Result<()> {
+        instructions::cancel_court_change(ctx, court_name)
+    }
+
+    pub fn set_paused(
+        ctx: Context<SetPaused>,
+        court_name: String,
+        paused: bool,
+    ) -> Result<()> {
+        instructions::set_paused(ctx, court_name, paused)
+    }
+
+    pub fn wind_down(
+        ctx: Context<WindDown>,
+        court_name: String,
+    ) -> Result<()> {
+        instructions::wind_down(ctx, court_name)
+    }
+
+    pub fn set_juror_allowlist(
+        ctx: Context<SetJurorAllowlist>,
+        court_name: String,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_juror_allowlist(ctx, court_name, enabled)
+    }
+
+    pub fn add_juror(
+        ctx: Context<AddJuror>,
+        court_name: String,
+    ) -> Result<()> {
+        instructions::add_juror(ctx, court_name)
+    }
+
+    pub fn remove_juror(
+        ctx: Context<RemoveJuror>,
+        court_name: String,
+    ) -> Result<()> {
+        instructions::remove_juror(ctx, court_name)
+    }
+
+    pub fn set_juror_collection(
+        ctx: Context<SetJurorCollection>,
+        court_name: String,
+        gate: Option<CollectionGate>,
+    ) -> Result<()> {
+        instructions::set_juror_collection(ctx, court_name, gate)
+    }
+
+    pub fn set_court_metadata(
+        ctx: Context<SetCourtMetadata>,
+        court_name: String,
+        display_name: String,
+        policy_uri: String,
+        policy_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::set_court_metadata(ctx, court_name, display_name, policy_uri, policy_hash)
+    }
+
+    pub fn initialize_registry(
+        ctx: Context<InitializeRegistry>,
+    ) -> Result<()> {
+        instructions::initialize_registry(ctx)
+    }
+
+    pub fn register_court(
+        ctx: Context<RegisterCourt>,
+        court_name: String,
+    ) -> Result<()> {
+        instructions::register_court(ctx, court_name)
+    }
+
+    pub fn wind_down_dispute(
+        ctx: Context<WindDownDispute>,
+        court_name: String,
+        dispute_id: u64,
+    ) -> Result<()> {
+        instructions::wind_down_dispute(ctx, court_name, dispute_id)
+    }
+
+    pub fn refund_protocol(
+        ctx: Context<RefundProtocol>,
+        court_name: String,
+        dispute_id: u64,
+    ) -> Result<()> {
+        instructions::refund_protocol(ctx, court_name, dispute_id)
     }
 
     pub fn initialize_dispute(
         ctx: Context<InitializeDispute>,
         court_name: String,
         users: Vec<Option<Pubkey>>,
-        config: DisputeConfiguration,
+        config: Option<DisputeConfiguration>,
+        party_costs: Vec<PartyCost>,
+        overrides: Option<DisputeOverrides>,
+    ) -> Result<()> {
+        instructions::initialize_dispute(ctx, court_name, users, config, party_costs, overrides)
+    }
+
+    pub fn initialize_appeal_fund(
+        ctx: Context<InitializeAppealFund>,
+        court_name: String,
+        dispute_id: u64,
+        config: AppealConfiguration,
+    ) -> Result<()> {
+        instructions::initialize_appeal_fund(ctx, court_name, dispute_id, config)
+    }
+
+    pub fn fund_appeal(
+        ctx: Context<FundAppeal>,
+        court_name: String,
+        dispute_id: u64,
+        side: u8,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::fund_appeal(ctx, court_name, dispute_id, side, amount)
+    }
+
+    pub fn claim_appeal_contribution(
+        ctx: Context<ClaimAppealContribution>,
+        court_name: String,
+        dispute_id: u64,
+        side: u8,
+    ) -> Result<()> {
+        instructions::claim_appeal_contribution(ctx, court_name, dispute_id, side)
+    }
+
+    pub fn deposit_stake(
+        ctx: Context<DepositStake>,
+        court_name: String,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::deposit_stake(ctx, court_name, amount)
+    }
+
+    pub fn request_unstake(
+        ctx: Context<RequestUnstake>,
+        court_name: String,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::request_unstake(ctx, court_name, amount)
+    }
+
+    pub fn withdraw_stake(
+        ctx: Context<WithdrawStake>,
+        court_name: String,
     ) -> Result<()> {
-        instructions::initialize_dispute(ctx, court_name, users, config)
+        instructions::withdraw_stake(ctx, court_name)
     }
 
     pub fn select_vote(
@@ -93,4 +328,83 @@ pub mod agora_court {
     ) -> Result<()> {
         instructions::initialize_record(ctx, court_name)
     }
+
+    pub fn add_protocol(
+        ctx: Context<AddProtocol>,
+        court_name: String,
+        defaults: Option<DisputeDefaults>,
+        rate_limit: Option<RateLimit>,
+    ) -> Result<()> {
+        instructions::add_protocol(ctx, court_name, defaults, rate_limit)
+    }
+
+    pub fn set_dispute_template(
+        ctx: Context<SetDisputeTemplate>,
+        court_name: String,
+        template_name: String,
+        defaults: DisputeDefaults,
+        overridable: u16,
+        policy: TemplatePolicy,
+    ) -> Result<()> {
+        instructions::set_dispute_template(ctx, court_name, template_name, defaults, overridable, policy)
+    }
+
+    pub fn set_config_bounds(
+        ctx: Context<SetConfigBounds>,
+        court_name: String,
+        bounds: Option<ConfigBounds>,
+    ) -> Result<()> {
+        instructions::set_config_bounds(ctx, court_name, bounds)
+    }
+
+    pub fn propose_transfer(
+        ctx: Context<ProposeTransfer>,
+        court_name: String,
+        authority: Option<Pubkey>,
+        protocol: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::propose_transfer(ctx, court_name, authority, protocol)
+    }
+
+    pub fn accept_authority(
+        ctx: Context<AcceptAuthority>,
+        court_name: String,
+    ) -> Result<()> {
+        instructions::accept_authority(ctx, court_name)
+    }
+
+    pub fn accept_protocol(
+        ctx: Context<AcceptProtocol>,
+        court_name: String,
+    ) -> Result<()> {
+        instructions::accept_protocol(ctx, court_name)
+    }
+
+    pub fn cancel_transfer(
+        ctx: Context<CancelTransfer>,
+        court_name: String,
+    ) -> Result<()> {
+        instructions::cancel_transfer(ctx, court_name)
+    }
+
+    pub fn remove_protocol(
+        ctx: Context<RemoveProtocol>,
+        court_name: String,
+    ) -> Result<()> {
+        instructions::remove_protocol(ctx, court_name)
+    }
+
+    pub fn resize_record(
+        ctx: Context<ResizeRecord>,
+        court_name: String,
+    ) -> Result<()> {
+        instructions::resize_record(ctx, court_name)
+    }
+
+    pub fn close_record(
+        ctx: Context<CloseRecord>,
+        court_name: String,
+    ) -> Result<()> {
+        instructions::close_record(ctx, court_name)
+    }
 }
@@ -0,0 +1,47 @@
+use crate::{tools::anchor::DISCRIMINATOR_SIZE, state::{EvidenceInput, EvidenceItem}};
+use anchor_lang::{prelude::*, solana_program::pubkey::PUBKEY_BYTES};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone)]
+pub struct AmicusConfig {
+    pub bond: u64,               // lamports a non-party posts with each submission
+    pub spam_threshold_bps: u16, // share of revealed jurors whose flags forfeit the bond
+}
+
+impl AmicusConfig {
+    pub const SIZE: usize = 8 + 2;
+}
+
+//evidence from a non-party, ["amicus", dispute, submitter] - the bond is held as lamports on the account
+#[account]
+pub struct AmicusSubmission {
+    pub dispute: Pubkey,
+    pub evidence: EvidenceItem,
+    pub bond: u64,               // court settings at submission
+    pub spam_threshold_bps: u16,
+    pub spam_flags: u32,         // jurors that flagged it, one AmicusFlag each
+    pub open_flags: u32,         // AmicusFlag accounts settle_amicus_bond has yet to close
+    pub bump: u8,
+}
+
+impl AmicusSubmission {
+    pub const BPS: u128 = 10_000;
+
+    pub fn get_size(input: &EvidenceInput) -> usize {
+        DISCRIMINATOR_SIZE + PUBKEY_BYTES + EvidenceItem::get_size(input) + 8 + 2 + 4 + 4 + 1
+    }
+
+    //jurors = revealed votes, the only juror count a concluded dispute keeps
+    pub fn is_spam(&self, jurors: u64) -> bool {
+        jurors > 0 && self.spam_flags as u128 * Self::BPS >= self.spam_threshold_bps as u128 * jurors as u128
+    }
+}
+
+//one per juror and submission, ["amicus_flag", submission, juror] - stops double flagging
+#[account]
+pub struct AmicusFlag {
+    pub bump: u8,
+}
+
+impl AmicusFlag {
+    pub const SIZE: usize = DISCRIMINATOR_SIZE + 1;
+}
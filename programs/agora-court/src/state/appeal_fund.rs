@@ -0,0 +1,113 @@
+use crate::{tools::anchor::DISCRIMINATOR_SIZE, error::InputError};
+use anchor_lang::{prelude::*, solana_program::pubkey::PUBKEY_BYTES};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone)]
+pub struct AppealConfiguration {
+    pub funding_ends_at: i64, // block time when contributions close
+    pub opening_ends_at: i64, // a funded appeal must be opened by then, after that every contribution is refunded
+    pub bond: u64,            // # of pay tokens each side needs to raise for the appeal to open
+    pub bonus_bps: u16,       // bonus for winning side contributors, paid out of the losing sides' unspent contributions
+    pub escalate: bool,       // appeal runs in the parent court instead of the dispute's own (sub-courts only)
+}
+
+impl AppealConfiguration {
+    pub const SIZE: usize = 8 + 8 + 8 + 2 + 1;
+}
+
+#[account]
+pub struct AppealFund {
+    pub court: Pubkey,
+    pub target_court: Pubkey,         // court the appeal dispute is opened in - court itself or its parent
+    pub dispute_id: u64,              // the concluded dispute being appealed
+    pub parties: Vec<Option<Pubkey>>, // sides, copied from the appealed dispute
+    pub funded: Vec<u64>,             // per parties index
+    pub config: AppealConfiguration,
+    pub status: AppealStatus,
+    pub bump: u8,
+}
+
+impl AppealFund {
+    pub fn get_size(parties: &[Option<Pubkey>]) -> usize {
+        DISCRIMINATOR_SIZE
+            + PUBKEY_BYTES
+            + PUBKEY_BYTES
+            + 8
+            + 4 + ((1 + PUBKEY_BYTES) * parties.len())
+            + 4 + (8 * parties.len())
+            + AppealConfiguration::SIZE
+            + AppealStatus::SIZE
+            + 1
+    }
+
+    pub fn fully_funded(&self) -> bool {
+        self.funded.iter().any(|&x| x >= self.config.bond)
+    }
+
+    //caps the contribution at what the side still needs, returns the accepted amount
+    pub fn contribute(&mut self, side: usize, amount: u64) -> Result<u64> {
+        let timestamp = Clock::get().unwrap().unix_timestamp;
+        require!(self.status == AppealStatus::Funding, InputError::AppealAlreadyOpened);
+        require!(timestamp < self.config.funding_ends_at, InputError::AppealFundingClosed);
+        require!(side < self.parties.len() && self.parties[side].is_some(), InputError::AppealSideInvalid);
+
+        let accepted = amount.min(self.config.bond.saturating_sub(self.funded[side]));
+        require!(accepted > 0, InputError::AppealSideFunded);
+
+        self.funded[side] += accepted;
+        Ok(accepted)
+    }
+
+    //appeal opens once funding has closed with at least one side fully funded
+    pub fn can_open(&self, users: &[Option<Pubkey>]) -> Result<()> {
+        let timestamp = Clock::get().unwrap().unix_timestamp;
+        require!(self.status == AppealStatus::Funding, InputError::AppealAlreadyOpened);
+        require!(timestamp > self.config.funding_ends_at, InputError::AppealFundingOpen);
+        require!(timestamp <= self.config.opening_ends_at, InputError::AppealOpeningClosed);
+        require!(self.fully_funded(), InputError::AppealNotFunded);
+        require!(users == self.parties.as_slice(), InputError::AppealDisputeMismatch);
+
+        Ok(())
+    }
+
+    //contributions can be refunded once the appeal can no longer open
+    pub fn expired(&self, timestamp: i64) -> bool {
+        timestamp > self.config.opening_ends_at
+            || (timestamp > self.config.funding_ends_at && !self.fully_funded())
+    }
+
+    //a fully funded side's bond is moved onto the appeal dispute when it opens
+    pub fn spent(&self, side: usize) -> bool {
+        self.funded[side] >= self.config.bond
+    }
+
+    //raised by every side except the winner that never reached the bond - still held by the fund
+    pub fn unspent_losing_total(&self, winning_side: usize) -> u64 {
+        self.funded.iter()
+            .enumerate()
+            .filter(|&(i, _)| i != winning_side && !self.spent(i))
+            .map(|(_, &x)| x)
+            .sum()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum AppealStatus {
+    Funding, //collecting contributions until funding_ends_at
+    Appealed { dispute_id: u64 }, //a side was fully funded, the appeal runs as a new dispute
+}
+
+impl AppealStatus {
+    pub const SIZE: usize = 1 + 8;
+}
+
+#[account]
+pub struct AppealContribution {
+    pub contributor: Pubkey,
+    pub side: u8,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+impl AppealContribution {
+    pub const SIZE: usize = DISCRIMINATOR_SIZE + PUBKEY_BYTES + 1 + 8 + 1;
+}
@@ -1,14 +1,68 @@
-use crate::tools::anchor::DISCRIMINATOR_SIZE;
-use anchor_lang::prelude::*;
-
-#[account]
-pub struct Case {
-    pub evidence: String,
-    pub bump: u8,
-}
-
-impl Case {
-    pub fn get_size(evidence: &String) -> usize {
-        DISCRIMINATOR_SIZE + (4 + evidence.len()) + 1
-    }
-}
+use crate::{tools::anchor::DISCRIMINATOR_SIZE, error::InputError};
+use anchor_lang::{prelude::*, solana_program::pubkey::PUBKEY_BYTES};
+
+pub const MAX_EVIDENCE_URI_LEN: usize = 200;
+pub const MAX_MIME_TYPE_LEN: usize = 64;
+
+//what a submitter provides, the program adds who and when
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone)]
+pub struct EvidenceInput {
+    pub uri: String,
+    pub content_hash: [u8; 32], // hash of the document at uri
+    pub mime_type: String,
+}
+
+impl EvidenceInput {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.uri.len() <= MAX_EVIDENCE_URI_LEN, InputError::EvidenceTooLong);
+        require!(self.mime_type.len() <= MAX_MIME_TYPE_LEN, InputError::EvidenceTooLong);
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone)]
+pub struct EvidenceItem {
+    pub uri: String,
+    pub content_hash: [u8; 32],
+    pub mime_type: String,
+    pub submitter: Pubkey,
+    pub submitted_at: i64,
+}
+
+impl EvidenceItem {
+    pub fn get_size(input: &EvidenceInput) -> usize {
+        (4 + input.uri.len()) + 32 + (4 + input.mime_type.len()) + PUBKEY_BYTES + 8
+    }
+
+    fn size(&self) -> usize {
+        (4 + self.uri.len()) + 32 + (4 + self.mime_type.len()) + PUBKEY_BYTES + 8
+    }
+}
+
+//append-only - items are only ever pushed (see add_evidence), the account grows with each one
+#[account]
+pub struct Case {
+    pub evidence: Vec<EvidenceItem>,
+    pub bump: u8,
+}
+
+impl Case {
+    pub fn get_size(evidence: &[EvidenceItem]) -> usize {
+        DISCRIMINATOR_SIZE + 4 + evidence.iter().map(|x| x.size()).sum::<usize>() + 1
+    }
+
+    //size once `input` has been appended
+    pub fn size_with(&self, input: &EvidenceInput) -> usize {
+        Self::get_size(&self.evidence) + EvidenceItem::get_size(input)
+    }
+
+    pub fn push(&mut self, input: EvidenceInput, submitter: Pubkey) {
+        self.evidence.push(EvidenceItem {
+            uri: input.uri,
+            content_hash: input.content_hash,
+            mime_type: input.mime_type,
+            submitter,
+            submitted_at: Clock::get().unwrap().unix_timestamp,
+        });
+    }
+}
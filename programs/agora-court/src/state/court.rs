@@ -1,5 +1,5 @@
-use crate::tools::anchor::DISCRIMINATOR_SIZE;
-use anchor_spl::token::Mint;
+use crate::{tools::anchor::DISCRIMINATOR_SIZE, error::InputError, state::{DisputeConfiguration, PartyCost, PendingCourtChange, AmicusConfig}};
+use anchor_spl::token::spl_token::native_mint;
 use anchor_lang::{prelude::*, solana_program::pubkey::PUBKEY_BYTES};
 
 #[account]
@@ -7,31 +7,132 @@ pub struct Court {
     pub edit_authority: Pubkey,
     pub protocol: Pubkey,
     pub rep_mint: Pubkey, //Reputation token mint - if no reputation, specify a barrier to entry mint for voters
-    pub pay_mint: Option<Pubkey>, //Mint to incentivize voters - can be the same as rep mint
+    pub pay_mint: Option<Pubkey>, //Mint to incentivize voters - can be the same as rep mint, native mint = raw SOL held on the dispute
     pub num_disputes: u64, //Tracks the number of disputes related to the protocol
     pub max_dispute_votes: u16, //Limits the number of simultaneous votes to disputes under same protocol
+    pub live_records: u64, //records with a non-empty claim queue - see check_queue_limit
+    pub unbonding_period: i64, //Seconds juror stake stays in the stake vault after request_unstake
+    pub crank_tip: u64, //Lamports paid from the court treasury PDA to keepers running crank_claim
+    pub soulbound: Option<SoulboundConfig>, //Set when the court owns its rep mint - rep is then minted/burned, never transferred
+    pub bounds: Option<ConfigBounds>, //Limits initialize_dispute enforces on every dispute config - None = unchecked
+    pub pending_authority: Option<Pubkey>, //Proposed edit authority, takes over once it signs accept_authority
+    pub pending_protocol: Option<Pubkey>, //Proposed protocol, takes over once it signs accept_protocol
+    pub change_delay: i64, //Seconds an edit_court change waits before apply_court_change can run
+    pub status: CourtStatus, //Paused / WindingDown block new disputes, interactions, cases and votes
+    pub parent: Option<Pubkey>, //Set on sub-courts - child jurors count as eligible in the parent, appeals can escalate to it
+    pub juror_allowlist: bool, //Only jurors with a JurorPass can vote
+    pub juror_collection: Option<CollectionGate>, //Jurors must hold an NFT from this verified Metaplex collection
+    pub policy_version: u32, //Bumped by set_court_metadata on every new policy hash, 0 = no policy published
+    pub amicus: Option<AmicusConfig>, //Set when non-parties may submit evidence, see submit_amicus_evidence
     pub bump: u8,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum CourtStatus {
+    Active,
+    Paused,      //reveal, close and claim still run, see set_paused
+    WindingDown, //permanent - open disputes get concluded without a winner, see wind_down_dispute
+}
+
+impl CourtStatus {
+    pub const SIZE: usize = 1;
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone)]
+pub struct CollectionGate {
+    pub collection: Pubkey, // verified collection mint the juror's NFT must belong to
+    pub replaces_rep: bool, // true = holding the NFT satisfies voter_rep_required, false = both are needed
+}
+
+impl CollectionGate {
+    pub const SIZE: usize = PUBKEY_BYTES + 1;
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone)]
+pub struct SoulboundConfig {
+    pub coherent_reward: u64,  // # of rep tokens minted to a voter who sided with the winner
+    pub incoherent_slash: u64, // # of rep tokens burned from a voter who did not (or never revealed)
+}
+
+impl SoulboundConfig {
+    pub const SIZE: usize = 8 + 8;
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone)]
+pub struct ConfigBounds {
+    pub min_phase_duration: i64,     // seconds, applies to each of grace / cases / voting / reveal
+    pub max_phase_duration: i64,
+    pub min_votes: u64,              // lowest quorum a dispute may ask for
+    pub max_voter_rep_required: u64,
+    pub max_voter_rep_cost: u64,
+    pub min_rep_cost: u64,           // per-party stakes, checked on every party cost
+    pub max_rep_cost: u64,
+    pub min_pay_cost: u64,
+    pub max_pay_cost: u64,
+    pub max_parties: u8,
+}
+
+impl ConfigBounds {
+    pub const SIZE: usize = 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1;
+
+    pub fn is_valid(&self) -> bool {
+        self.min_phase_duration > 0
+            && self.min_phase_duration <= self.max_phase_duration
+            && self.min_rep_cost <= self.max_rep_cost
+            && self.min_pay_cost <= self.max_pay_cost
+            && self.max_parties > 0
+    }
+
+    pub fn check(&self, config: &DisputeConfiguration, timestamp: i64, parties: usize, party_costs: &[PartyCost]) -> Result<()> {
+        require!(parties <= self.max_parties as usize, InputError::TooManyParties);
+
+        let phases = [
+            config.grace_ends_at - timestamp,
+            config.init_cases_ends_at - config.grace_ends_at,
+            config.voting_ends_at - config.init_cases_ends_at,
+            config.dispute_ends_at - config.voting_ends_at,
+        ];
+        for phase in phases {
+            require!(phase >= self.min_phase_duration, InputError::PhaseTooShort);
+            require!(phase <= self.max_phase_duration, InputError::PhaseTooLong);
+        }
+
+        require!(config.min_votes >= self.min_votes, InputError::QuorumTooLow);
+        require!(config.voter_rep_required <= self.max_voter_rep_required, InputError::VoterRepRequiredTooHigh);
+        require!(config.voter_rep_cost <= self.max_voter_rep_cost, InputError::VoterRepCostTooHigh);
+
+        for cost in party_costs {
+            require!(self.min_rep_cost <= cost.rep && cost.rep <= self.max_rep_cost, InputError::PartyRepCostOutOfBounds);
+            require!(self.min_pay_cost <= cost.pay && cost.pay <= self.max_pay_cost, InputError::PartyPayCostOutOfBounds);
+        }
+
+        Ok(())
+    }
+}
+
 impl Court {
-    pub const SIZE: usize = DISCRIMINATOR_SIZE + PUBKEY_BYTES + PUBKEY_BYTES + PUBKEY_BYTES + (PUBKEY_BYTES + 1) + 8 + 2 + 1;
-
-    pub fn edit(
-        &mut self, 
-        votes: u16,
-        authority: Pubkey,
-        protocol: Pubkey,
-        rep_mint: Pubkey,
-        pay_mint: &Option<Account<'_, Mint>>,
-    ) {
-        self.max_dispute_votes = votes;
-        self.edit_authority = authority;
-        self.protocol = protocol;
-        self.rep_mint = rep_mint;
-
-        self.pay_mint = match pay_mint {
-            Some(n) => Some(n.key()),
-            None => None
-        };
+    pub const SIZE: usize = DISCRIMINATOR_SIZE + PUBKEY_BYTES + PUBKEY_BYTES + PUBKEY_BYTES + (PUBKEY_BYTES + 1) + 8 + 2 + 8 + 8 + 8 + (1 + SoulboundConfig::SIZE) + (1 + ConfigBounds::SIZE) + (1 + PUBKEY_BYTES) + (1 + PUBKEY_BYTES) + 8 + CourtStatus::SIZE + (1 + PUBKEY_BYTES) + 1 + (1 + CollectionGate::SIZE) + 4 + (1 + AmicusConfig::SIZE) + 1;
+
+    //pay is held as lamports on the dispute account instead of in a token vault
+    pub fn pays_native(&self) -> bool {
+        self.pay_mint == Some(native_mint::ID)
+    }
+
+    //a record counts as live from its first queued dispute until its queue is empty again
+    pub fn track_record(&mut self, was_live: bool, is_live: bool) {
+        match (was_live, is_live) {
+            (false, true) => self.live_records = self.live_records.saturating_add(1),
+            (true, false) => self.live_records = self.live_records.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    pub fn edit(&mut self, change: &PendingCourtChange) {
+        self.max_dispute_votes = change.max_dispute_votes;
+        self.unbonding_period = change.unbonding_period;
+        self.crank_tip = change.crank_tip;
+        self.change_delay = change.change_delay;
+        self.rep_mint = change.rep_mint;
+        self.pay_mint = change.pay_mint;
     }
 }
\ No newline at end of file
@@ -0,0 +1,61 @@
+use crate::tools::anchor::DISCRIMINATOR_SIZE;
+use anchor_lang::{prelude::*, solana_program::pubkey::PUBKEY_BYTES};
+
+pub const MAX_DISPLAY_NAME_LEN: usize = 64;
+pub const MAX_POLICY_URI_LEN: usize = 200;
+pub const MAX_COURT_NAME_LEN: usize = 32; // court names are a PDA seed
+
+//human-facing description of a court and its rules, ["court_metadata", court]
+#[account]
+pub struct CourtMetadata {
+    pub court: Pubkey,
+    pub display_name: String,
+    pub policy_uri: String,       // where the court's rules are published
+    pub policy_hash: [u8; 32],    // hash of the document at policy_uri
+    pub policy_version: u32,      // mirrors court.policy_version
+    pub bump: u8,
+}
+
+impl CourtMetadata {
+    pub const SIZE: usize = DISCRIMINATOR_SIZE
+        + PUBKEY_BYTES
+        + 4 + MAX_DISPLAY_NAME_LEN
+        + 4 + MAX_POLICY_URI_LEN
+        + 32
+        + 4
+        + 1;
+}
+
+//global index of courts, ["registry"] - clients page through the entries by index
+#[account]
+pub struct CourtRegistry {
+    pub num_courts: u64,
+    pub bump: u8,
+}
+
+impl CourtRegistry {
+    pub const SIZE: usize = DISCRIMINATOR_SIZE + 8 + 1;
+}
+
+//one registered court, ["registry", index] - the name is what the court's own seeds need
+#[account]
+pub struct RegistryEntry {
+    pub court: Pubkey,
+    pub court_name: String,
+    pub bump: u8,
+}
+
+impl RegistryEntry {
+    pub const SIZE: usize = DISCRIMINATOR_SIZE + PUBKEY_BYTES + 4 + MAX_COURT_NAME_LEN + 1;
+}
+
+//reverse lookup, ["registry_listing", court] - also stops a court from being registered twice
+#[account]
+pub struct RegistryListing {
+    pub index: u64,
+    pub bump: u8,
+}
+
+impl RegistryListing {
+    pub const SIZE: usize = DISCRIMINATOR_SIZE + 8 + 1;
+}
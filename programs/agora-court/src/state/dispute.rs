@@ -1,4 +1,5 @@
-use crate::{tools::anchor::DISCRIMINATOR_SIZE, error::InputError};
+use crate::{tools::anchor::DISCRIMINATOR_SIZE, error::InputError, state::SoulboundConfig};
+use anchor_spl::token::spl_token::native_mint;
 use anchor_lang::{prelude::*, solana_program::pubkey::PUBKEY_BYTES};
 
 #[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone)]
@@ -20,14 +21,34 @@ impl DisputeConfiguration {
     pub const SIZE: usize = 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8;
 }
 
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PartyCost {
+    pub rep: u64, // # of rep tokens this party deposits
+    pub pay: u64, // # of pay tokens this party deposits
+}
+
+impl PartyCost {
+    pub const SIZE: usize = 8 + 8;
+}
+
 #[account]
 pub struct Dispute {
     pub users: Vec<Option<Pubkey>>,
     pub votes: Vec<u64>,
+    pub committed_votes: u64,        // every vote committed, revealed or not - each locked voter_rep_cost
     pub status: DisputeStatus,
     pub interactions: u8,
     pub submitted_cases: u8,
     pub config: DisputeConfiguration,
+    pub party_costs: Vec<PartyCost>, // per users index, defaults to config rep_cost/pay_cost
+    pub cases: Vec<bool>,            // per users index, whether that party submitted a case
+    pub appeal_bonds: Vec<u64>,      // per users index, appeal bond received for that side (appeals only)
+    pub case_stakes: PartyCost,      // total deposited by parties that submitted a case
+    pub protocol: Pubkey,            // protocol that opened the dispute
+    pub rep_mint: Pubkey,                   // court settings at creation - later instructions check these,
+    pub pay_mint: Option<Pubkey>,           // so edit_court can't strand the dispute's vaults
+    pub soulbound: Option<SoulboundConfig>,
+    pub policy_version: u32,         // court policy in force at creation, see set_court_metadata
     pub bump: u8,
 }
 //note to self: need better flow checks between DisputeStatus enums (same issue aggregated from interact.rs warning)
@@ -38,6 +59,23 @@ impl Dispute {
         Ok(())
     }
 
+    pub fn party_index(&self, user: Pubkey) -> Option<usize> {
+        self.users.iter().position(|&x| x == Some(user))
+    }
+
+    pub fn party_cost(&self, user: Pubkey) -> Option<PartyCost> {
+        self.party_index(user).map(|idx| self.party_costs[idx])
+    }
+
+    pub fn has_case(&self, user: Pubkey) -> bool {
+        self.party_index(user).is_some_and(|idx| self.cases[idx])
+    }
+
+    //pay is held as lamports on the dispute account instead of in a token vault
+    pub fn pays_native(&self) -> bool {
+        self.pay_mint == Some(native_mint::ID)
+    }
+
     pub fn total_votes(&self) -> u64 {
         self.votes.iter().sum()
     }
@@ -50,12 +88,32 @@ impl Dispute {
         DISCRIMINATOR_SIZE
             + 4 + ((1 + PUBKEY_BYTES) * users.len())
             + 4 + (8 * users.len())
+            + 8
             + DisputeStatus::SIZE
             + 1 + 1
             + DisputeConfiguration::SIZE
+            + 4 + (PartyCost::SIZE * users.len())
+            + 4 + users.len()
+            + 4 + (8 * users.len())
+            + PartyCost::SIZE
+            + PUBKEY_BYTES
+            + PUBKEY_BYTES
+            + (1 + PUBKEY_BYTES)
+            + (1 + SoulboundConfig::SIZE)
+            + 4
             + 1
     }
 
+    //evidence on top of cases - only while cases are still being collected, before voting starts
+    pub fn can_add_evidence(&self) -> Result<()> {
+        let timestamp = Clock::get().unwrap().unix_timestamp;
+        require!(
+            timestamp < self.config.init_cases_ends_at && matches!(self.status, DisputeStatus::Grace | DisputeStatus::Waiting),
+            InputError::CasesNoLongerCanBeSubmitted
+        );
+        Ok(())
+    }
+
     pub fn can_add_case(&mut self) -> Result<()> {
         let timestamp = Clock::get().unwrap().unix_timestamp;
         match self.status {
@@ -117,6 +175,13 @@ impl Dispute {
         err!(InputError::NotRevealPeriod)
     }
 
+    //court wind-down: concludes without a winner so every party and voter is refunded on claim
+    pub fn wind_down(&mut self) -> Result<()> {
+        require!(!matches!(self.status, DisputeStatus::Concluded { .. }), InputError::DisputeAlreadyConcluded);
+        self.status = DisputeStatus::Concluded { winner: None };
+        Ok(())
+    }
+
     pub fn can_close(&mut self) -> Result<()> {
         let timestamp = Clock::get().unwrap().unix_timestamp;
 
@@ -0,0 +1,106 @@
+use crate::{tools::anchor::DISCRIMINATOR_SIZE, error::InputError, state::{DisputeDefaults, DisputeConfiguration, ConfigBounds, PartyCost}};
+use anchor_lang::{prelude::*, solana_program::pubkey::PUBKEY_BYTES};
+
+//bits of DisputeTemplate.overridable, one per DisputeOverrides field
+pub const OVERRIDE_GRACE_DURATION: u16 = 1 << 0;
+pub const OVERRIDE_INIT_CASES_DURATION: u16 = 1 << 1;
+pub const OVERRIDE_VOTING_DURATION: u16 = 1 << 2;
+pub const OVERRIDE_REVEAL_DURATION: u16 = 1 << 3;
+pub const OVERRIDE_VOTER_REP_REQUIRED: u16 = 1 << 4;
+pub const OVERRIDE_VOTER_REP_COST: u16 = 1 << 5;
+pub const OVERRIDE_REP_COST: u16 = 1 << 6;
+pub const OVERRIDE_PAY_COST: u16 = 1 << 7;
+pub const OVERRIDE_MIN_VOTES: u16 = 1 << 8;
+pub const OVERRIDE_PROTOCOL_PAY: u16 = 1 << 9;
+pub const OVERRIDE_PROTOCOL_REP: u16 = 1 << 10;
+
+//named preset under a court, ["template", court, name]
+#[account]
+pub struct DisputeTemplate {
+    pub court: Pubkey,
+    pub defaults: DisputeDefaults,
+    pub overridable: u16, // OVERRIDE_* bits the protocol may change per dispute
+    pub policy: TemplatePolicy,
+    pub bump: u8,
+}
+
+impl DisputeTemplate {
+    pub const SIZE: usize = DISCRIMINATOR_SIZE + PUBKEY_BYTES + DisputeDefaults::SIZE + 2 + TemplatePolicy::SIZE + 1;
+
+    //template values with the overrides applied, failing on any field the court locked
+    pub fn apply(&self, overrides: &DisputeOverrides) -> Result<DisputeDefaults> {
+        let mut out = self.defaults.clone();
+
+        override_field(&mut out.grace_duration, overrides.grace_duration, self.overridable, OVERRIDE_GRACE_DURATION)?;
+        override_field(&mut out.init_cases_duration, overrides.init_cases_duration, self.overridable, OVERRIDE_INIT_CASES_DURATION)?;
+        override_field(&mut out.voting_duration, overrides.voting_duration, self.overridable, OVERRIDE_VOTING_DURATION)?;
+        override_field(&mut out.reveal_duration, overrides.reveal_duration, self.overridable, OVERRIDE_REVEAL_DURATION)?;
+        override_field(&mut out.voter_rep_required, overrides.voter_rep_required, self.overridable, OVERRIDE_VOTER_REP_REQUIRED)?;
+        override_field(&mut out.voter_rep_cost, overrides.voter_rep_cost, self.overridable, OVERRIDE_VOTER_REP_COST)?;
+        override_field(&mut out.rep_cost, overrides.rep_cost, self.overridable, OVERRIDE_REP_COST)?;
+        override_field(&mut out.pay_cost, overrides.pay_cost, self.overridable, OVERRIDE_PAY_COST)?;
+        override_field(&mut out.min_votes, overrides.min_votes, self.overridable, OVERRIDE_MIN_VOTES)?;
+        override_field(&mut out.protocol_pay, overrides.protocol_pay, self.overridable, OVERRIDE_PROTOCOL_PAY)?;
+        override_field(&mut out.protocol_rep, overrides.protocol_rep, self.overridable, OVERRIDE_PROTOCOL_REP)?;
+
+        Ok(out)
+    }
+}
+
+//rules a dispute opened from the template must follow, never overridable
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct TemplatePolicy {
+    pub bounds: Option<ConfigBounds>, // limits on the config after overrides, checked on top of court.bounds
+    pub allow_party_costs: bool,      // false = every party stakes the template's rep_cost / pay_cost
+    pub allow_open_slots: bool,       // false = every user must be named up front, no None slots
+}
+
+impl TemplatePolicy {
+    pub const SIZE: usize = (1 + ConfigBounds::SIZE) + 1 + 1;
+
+    pub fn is_valid(&self) -> bool {
+        self.bounds.as_ref().is_none_or(|bounds| bounds.is_valid())
+    }
+
+    pub fn check(
+        &self,
+        config: &DisputeConfiguration,
+        timestamp: i64,
+        users: &[Option<Pubkey>],
+        party_costs: &[PartyCost],
+        custom_costs: bool
+    ) -> Result<()> {
+        require!(self.allow_party_costs || !custom_costs, InputError::TemplatePartyCostsLocked);
+        require!(self.allow_open_slots || users.iter().all(|user| user.is_some()), InputError::TemplateOpenSlotsLocked);
+
+        if let Some(bounds) = &self.bounds {
+            bounds.check(config, timestamp, users.len(), party_costs)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn override_field<T>(field: &mut T, value: Option<T>, overridable: u16, bit: u16) -> Result<()> {
+    if let Some(x) = value {
+        require!(overridable & bit != 0, InputError::TemplateFieldLocked);
+        *field = x;
+    }
+    Ok(())
+}
+
+//per-dispute changes to a template, None keeps the template value
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Default)]
+pub struct DisputeOverrides {
+    pub grace_duration: Option<i64>,
+    pub init_cases_duration: Option<i64>,
+    pub voting_duration: Option<i64>,
+    pub reveal_duration: Option<i64>,
+    pub voter_rep_required: Option<u64>,
+    pub voter_rep_cost: Option<u64>,
+    pub rep_cost: Option<u64>,
+    pub pay_cost: Option<u64>,
+    pub min_votes: Option<u64>,
+    pub protocol_pay: Option<u64>,
+    pub protocol_rep: Option<u64>,
+}
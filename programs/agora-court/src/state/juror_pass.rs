@@ -0,0 +1,13 @@
+use crate::tools::anchor::DISCRIMINATOR_SIZE;
+use anchor_lang::{prelude::*, solana_program::pubkey::PUBKEY_BYTES};
+
+//allow-list membership, ["juror", court, juror] - only checked while court.juror_allowlist is on
+#[account]
+pub struct JurorPass {
+    pub juror: Pubkey,
+    pub bump: u8,
+}
+
+impl JurorPass {
+    pub const SIZE: usize = DISCRIMINATOR_SIZE + PUBKEY_BYTES + 1;
+}
@@ -9,3 +9,24 @@ pub mod case;
 
 pub use voter_record::*;
 pub mod voter_record;
+
+pub use appeal_fund::*;
+pub mod appeal_fund;
+
+pub use protocol_entry::*;
+pub mod protocol_entry;
+
+pub use dispute_template::*;
+pub mod dispute_template;
+
+pub use pending_court_change::*;
+pub mod pending_court_change;
+
+pub use juror_pass::*;
+pub mod juror_pass;
+
+pub use court_metadata::*;
+pub mod court_metadata;
+
+pub use amicus::*;
+pub mod amicus;
@@ -0,0 +1,32 @@
+use crate::tools::anchor::DISCRIMINATOR_SIZE;
+use anchor_lang::{prelude::*, solana_program::pubkey::PUBKEY_BYTES};
+
+//court settings queued by edit_court, ["court_change", court]
+//applied as a whole by apply_court_change once effective_at has passed
+#[account]
+pub struct PendingCourtChange {
+    pub court: Pubkey,
+    pub proposer: Pubkey, // edit authority that queued it, gets the rent back
+    pub max_dispute_votes: u16,
+    pub unbonding_period: i64,
+    pub crank_tip: u64,
+    pub change_delay: i64,
+    pub rep_mint: Pubkey,
+    pub pay_mint: Option<Pubkey>,
+    pub effective_at: i64,
+    pub bump: u8,
+}
+
+impl PendingCourtChange {
+    pub const SIZE: usize = DISCRIMINATOR_SIZE
+        + PUBKEY_BYTES
+        + PUBKEY_BYTES
+        + 2
+        + 8
+        + 8
+        + 8
+        + PUBKEY_BYTES
+        + (1 + PUBKEY_BYTES)
+        + 8
+        + 1;
+}
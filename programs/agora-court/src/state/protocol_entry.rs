@@ -0,0 +1,89 @@
+use crate::{tools::anchor::DISCRIMINATOR_SIZE, error::InputError, state::DisputeConfiguration};
+use anchor_lang::{prelude::*, solana_program::pubkey::PUBKEY_BYTES};
+
+//durations instead of end times, turned into a DisputeConfiguration when the dispute is created
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone)]
+pub struct DisputeDefaults {
+    pub grace_duration: i64,      // seconds after creation users can interact
+    pub init_cases_duration: i64, // seconds after grace users can submit cases
+    pub voting_duration: i64,     // seconds after cases voting is open
+    pub reveal_duration: i64,     // seconds after voting votes can be revealed
+    pub voter_rep_required: u64,
+    pub voter_rep_cost: u64,
+    pub rep_cost: u64,
+    pub pay_cost: u64,
+    pub min_votes: u64,
+    pub protocol_pay: u64,
+    pub protocol_rep: u64,
+}
+
+impl DisputeDefaults {
+    pub const SIZE: usize = 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8;
+
+    pub fn to_config(&self, timestamp: i64) -> Result<DisputeConfiguration> {
+        let grace_ends_at = timestamp.checked_add(self.grace_duration).ok_or(InputError::InvalidEndTime)?;
+        let init_cases_ends_at = grace_ends_at.checked_add(self.init_cases_duration).ok_or(InputError::InvalidEndTime)?;
+        let voting_ends_at = init_cases_ends_at.checked_add(self.voting_duration).ok_or(InputError::InvalidEndTime)?;
+        let dispute_ends_at = voting_ends_at.checked_add(self.reveal_duration).ok_or(InputError::InvalidEndTime)?;
+
+        Ok(DisputeConfiguration {
+            grace_ends_at,
+            init_cases_ends_at,
+            voting_ends_at,
+            dispute_ends_at,
+            voter_rep_required: self.voter_rep_required,
+            voter_rep_cost: self.voter_rep_cost,
+            rep_cost: self.rep_cost,
+            pay_cost: self.pay_cost,
+            min_votes: self.min_votes,
+            protocol_pay: self.protocol_pay,
+            protocol_rep: self.protocol_rep,
+        })
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone)]
+pub struct RateLimit {
+    pub max_disputes: u32, // disputes the protocol may open per window
+    pub window: i64,       // window length in seconds
+}
+
+impl RateLimit {
+    pub const SIZE: usize = 4 + 8;
+}
+
+//one per protocol allowed to open disputes in a court, besides court.protocol itself
+#[account]
+pub struct ProtocolEntry {
+    pub protocol: Pubkey,
+    pub defaults: Option<DisputeDefaults>, // used when initialize_dispute gets no config
+    pub rate_limit: Option<RateLimit>,
+    pub window_started_at: i64,
+    pub window_disputes: u32,
+    pub bump: u8,
+}
+
+impl ProtocolEntry {
+    pub const SIZE: usize = DISCRIMINATOR_SIZE
+        + PUBKEY_BYTES
+        + (1 + DisputeDefaults::SIZE)
+        + (1 + RateLimit::SIZE)
+        + 8
+        + 4
+        + 1;
+
+    pub fn record_dispute(&mut self) -> Result<()> {
+        if let Some(limit) = &self.rate_limit {
+            let timestamp = Clock::get().unwrap().unix_timestamp;
+            if timestamp >= self.window_started_at.saturating_add(limit.window) {
+                self.window_started_at = timestamp;
+                self.window_disputes = 0;
+            }
+
+            require!(self.window_disputes < limit.max_disputes, InputError::ProtocolRateLimited);
+        }
+
+        self.window_disputes = self.window_disputes.saturating_add(1);
+        Ok(())
+    }
+}
@@ -22,18 +22,88 @@ impl DisputeRecord {
     pub const SIZE: usize = 8 + 8 + (1 + PUBKEY_BYTES);
 }
 
+//juror rep deposited into the court stake vault - disputes lock and release it instead of moving tokens
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, Default)]
+pub struct JurorStake {
+    pub free: u64,              // available to back new votes or to unstake
+    pub locked: u64,            // backing votes in disputes that have not been claimed yet
+    pub unbonding: u64,         // requested for withdrawal, no longer counts towards eligibility
+    pub unbonding_ends_at: i64, // block time when the unbonding amount can be withdrawn
+}
+
+impl JurorStake {
+    pub const SIZE: usize = 8 + 8 + 8 + 8;
+
+    pub fn deposit(&mut self, amount: u64) -> Result<()> {
+        self.free = self.free.checked_add(amount).ok_or(InputError::StakeOverflow)?;
+        Ok(())
+    }
+
+    pub fn lock(&mut self, amount: u64) -> Result<()> {
+        self.free = self.free.checked_sub(amount).ok_or(InputError::InsufficientFreeStake)?;
+        self.locked = self.locked.checked_add(amount).ok_or(InputError::StakeOverflow)?;
+        Ok(())
+    }
+
+    //releases a dispute's lock, crediting back whatever the juror earned (0 when slashed)
+    pub fn release(&mut self, amount: u64, credited: u64) -> Result<()> {
+        self.locked = self.locked.checked_sub(amount).ok_or(InputError::StakeOverflow)?;
+        self.free = self.free.checked_add(credited).ok_or(InputError::StakeOverflow)?;
+        Ok(())
+    }
+
+    //requesting again restarts the unbonding period for the whole unbonding amount
+    pub fn request_unstake(&mut self, amount: u64, unbonding_period: i64) -> Result<()> {
+        let timestamp = Clock::get().unwrap().unix_timestamp;
+        self.free = self.free.checked_sub(amount).ok_or(InputError::InsufficientFreeStake)?;
+        self.unbonding = self.unbonding.checked_add(amount).ok_or(InputError::StakeOverflow)?;
+        self.unbonding_ends_at = timestamp.checked_add(unbonding_period).ok_or(InputError::StakeOverflow)?;
+        Ok(())
+    }
+
+    pub fn withdraw(&mut self) -> Result<u64> {
+        let timestamp = Clock::get().unwrap().unix_timestamp;
+        require!(self.unbonding > 0, InputError::NothingToWithdraw);
+        require!(timestamp >= self.unbonding_ends_at, InputError::UnbondingNotFinished);
+
+        let amount = self.unbonding;
+        self.unbonding = 0;
+        Ok(amount)
+    }
+}
+
 #[account]
 pub struct VoterRecord {
     // claim_queue represents the disputes that the user is a participant of in descending order of dispute end time.
     pub claim_queue: Vec<DisputeRecord>,    //replaced binary heap with vec, fastest min heap i could get working for now
     pub currently_staked_rep: u64,
     pub currently_staked_pay: u64,
+    pub juror_stake: JurorStake,
+    pub rep_debt: u64, //soulbound slashes crank_claim couldn't burn - netted against later mints, else burned on the next signed claim
     pub bump: u8,
 }
 
 impl VoterRecord {
     pub fn get_size(max_disputes: u16) -> usize {
-        DISCRIMINATOR_SIZE + 4 + (DisputeRecord::SIZE * (max_disputes as usize)) + 8 + 8 + 1 //includes zero entry overhead, no realloc functionality (yet)
+        DISCRIMINATOR_SIZE + 4 + (DisputeRecord::SIZE * (max_disputes as usize)) + 8 + 8 + JurorStake::SIZE + 8 + 1 //includes zero entry overhead, see resize_record for court limit changes
+    }
+
+    //nothing left to claim and no tokens tracked against the record
+    pub fn is_settled(&self) -> bool {
+        self.claim_queue.is_empty()
+            && self.currently_staked_rep == 0
+            && self.currently_staked_pay == 0
+            && self.juror_stake.free == 0
+            && self.juror_stake.locked == 0
+            && self.juror_stake.unbonding == 0
+            && self.rep_debt == 0
+    }
+
+    //nets a soulbound mint against outstanding debt, returns what is still left to mint
+    pub fn offset_rep_debt(&mut self, rep_to_mint: u64) -> u64 {
+        let offset = rep_to_mint.min(self.rep_debt);
+        self.rep_debt -= offset;
+        rep_to_mint - offset
     }
 
     pub fn in_dispute(&self, dispute_id: u64) -> bool {
@@ -42,12 +112,34 @@ impl VoterRecord {
             .any(|record| record.dispute_id == dispute_id)
     }
 
-    pub fn has_unclaimed_disputes(&self) -> bool {
-        if let Some(record) = self.peek() {
-            record.dispute_end_time < Clock::get().unwrap().unix_timestamp 
-        } else {
-            false
-        }
+    //ended entries block new votes / cases until claimed, unless their dispute is shown to still be open
+    //(never closed) - see tools/records.rs
+    pub fn has_unclaimed_disputes(&self, open_disputes: &[u64]) -> bool {
+        let timestamp = Clock::get().unwrap().unix_timestamp;
+        self.claim_queue
+            .iter()
+            .any(|record| record.dispute_end_time < timestamp && !open_disputes.contains(&record.dispute_id))
+    }
+
+    pub fn ended_disputes(&self) -> Vec<u64> {
+        let timestamp = Clock::get().unwrap().unix_timestamp;
+        self.claim_queue
+            .iter()
+            .filter(|record| record.dispute_end_time < timestamp)
+            .map(|record| record.dispute_id)
+            .collect()
+    }
+
+    pub fn stake(&mut self, rep: u64, pay: u64) -> Result<()> {
+        self.currently_staked_rep = self.currently_staked_rep.checked_add(rep).ok_or(InputError::StakeOverflow)?;
+        self.currently_staked_pay = self.currently_staked_pay.checked_add(pay).ok_or(InputError::StakeOverflow)?;
+        Ok(())
+    }
+
+    pub fn unstake(&mut self, rep: u64, pay: u64) -> Result<()> {
+        self.currently_staked_rep = self.currently_staked_rep.checked_sub(rep).ok_or(InputError::StakeOverflow)?;
+        self.currently_staked_pay = self.currently_staked_pay.checked_sub(pay).ok_or(InputError::StakeOverflow)?;
+        Ok(())
     }
 
     pub fn verify_hash(&mut self, pubkey: Pubkey, salt: &String, dispute_id: u64) -> Result<()> {
@@ -93,6 +185,11 @@ impl VoterRecord {
         self.claim_queue.insert(pos, item);
     }
 
+    pub fn remove(&mut self, dispute_id: u64) -> Option<DisputeRecord> {
+        let idx = self.claim_queue.iter().position(|record| record.dispute_id == dispute_id)?;
+        Some(self.claim_queue.remove(idx))
+    }
+
     pub fn pop(&mut self) -> Option<DisputeRecord> {
         self.claim_queue.pop()
     }
@@ -0,0 +1,98 @@
+use crate::error::InputError;
+use anchor_lang::prelude::*;
+
+//minimal read of Metaplex token metadata - only the fields up to `collection`, borsh stops there.
+//the layout is stable across metadata versions, so this avoids pulling in mpl-token-metadata.
+
+pub mod metadata_program {
+    anchor_lang::declare_id!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone)]
+pub struct Creator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub share: u8,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone)]
+pub struct Collection {
+    pub verified: bool,
+    pub key: Pubkey,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone)]
+pub struct MetadataPrefix {
+    pub key: u8,
+    pub update_authority: Pubkey,
+    pub mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub creators: Option<Vec<Creator>>,
+    pub primary_sale_happened: bool,
+    pub is_mutable: bool,
+    pub edition_nonce: Option<u8>,
+    pub token_standard: Option<u8>,
+    pub collection: Option<Collection>,
+}
+
+//true if `metadata` is the metadata PDA of `mint` and lists it as a verified member of `collection`
+pub fn in_verified_collection(metadata: &AccountInfo, mint: &Pubkey, collection: &Pubkey) -> Result<bool> {
+    let (expected, _) = Pubkey::find_program_address(
+        &["metadata".as_bytes(), metadata_program::ID.as_ref(), mint.as_ref()],
+        &metadata_program::ID
+    );
+    require!(metadata.key() == expected && *metadata.owner == metadata_program::ID, InputError::CollectionMetadataMismatch);
+
+    let data = metadata.try_borrow_data()?;
+    let parsed = MetadataPrefix::deserialize(&mut &data[..]).map_err(|_| InputError::CollectionMetadataMismatch)?;
+
+    Ok(parsed.mint == *mint && is_verified_member(&parsed, collection))
+}
+
+fn is_verified_member(metadata: &MetadataPrefix, collection: &Pubkey) -> bool {
+    matches!(&metadata.collection, Some(x) if x.verified && x.key == *collection)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(collection: Option<Collection>) -> MetadataPrefix {
+        MetadataPrefix {
+            key: 4,
+            update_authority: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            name: "pass\0\0\0\0".to_string(), // metaplex pads strings with nulls
+            symbol: "DAO".to_string(),
+            uri: "https://example.com".to_string(),
+            seller_fee_basis_points: 0,
+            creators: Some(vec![Creator { address: Pubkey::new_unique(), verified: true, share: 100 }]),
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: Some(255),
+            token_standard: Some(0),
+            collection,
+        }
+    }
+
+    #[test]
+    fn parses_collection_with_trailing_fields() {
+        let key = Pubkey::new_unique();
+        let mut data = metadata(Some(Collection { verified: true, key })).try_to_vec().unwrap();
+        data.extend_from_slice(&[0; 64]); // uses, collection_details, ... follow on chain
+
+        let parsed = MetadataPrefix::deserialize(&mut &data[..]).unwrap();
+        assert!(is_verified_member(&parsed, &key));
+        assert!(!is_verified_member(&parsed, &Pubkey::new_unique()));
+    }
+
+    #[test]
+    fn unverified_or_missing_collection_is_rejected() {
+        let key = Pubkey::new_unique();
+        assert!(!is_verified_member(&metadata(Some(Collection { verified: false, key })), &key));
+        assert!(!is_verified_member(&metadata(None), &key));
+    }
+}
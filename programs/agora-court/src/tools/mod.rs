@@ -1 +1,7 @@
-pub mod anchor;
\ No newline at end of file
+pub mod anchor;
+pub mod reputation;
+pub mod payout;
+pub mod token;
+pub mod native;
+pub mod records;
+pub mod metadata;
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::error::InputError;
+
+//Native SOL pay flow - used when the court's pay mint is the native mint.
+//Lamports are held directly on the (program owned) dispute account next to its rent,
+//so withdrawals may only ever take what sits above the rent exempt minimum.
+
+pub fn deposit_lamports<'info>(
+    system_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            system_program.clone(),
+            Transfer {
+                from: from.clone(),
+                to: to.clone(),
+            },
+        ),
+        amount,
+    )
+}
+
+pub fn withdraw_lamports<'info>(
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(from.data_len());
+    let remaining = from.lamports().checked_sub(amount).ok_or(InputError::InsufficientVaultLamports)?;
+    require!(remaining >= rent, InputError::InsufficientVaultLamports);
+
+    **from.try_borrow_mut_lamports()? = remaining;
+    **to.try_borrow_mut_lamports()? = to.lamports().checked_add(amount).ok_or(InputError::PayoutOverflow)?;
+
+    Ok(())
+}
+
+//pays from a system owned PDA (e.g. the court treasury), skipping when it can't cover amount + rent
+//returns what was actually paid
+pub fn pay_from_pda<'info>(
+    system_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<u64> {
+    let rent = Rent::get()?.minimum_balance(0);
+    if amount == 0 || from.lamports() < amount.saturating_add(rent) {
+        return Ok(0);
+    }
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Transfer {
+                from: from.clone(),
+                to: to.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    Ok(amount)
+}
@@ -0,0 +1,372 @@
+use anchor_lang::prelude::*;
+use crate::{error::InputError, state::{Dispute, DisputeStatus, PartyCost}};
+
+//Pure payout maths for claim. Everything is checked, and every token in the
+//distributable pool ends up with exactly one recipient:
+//  pool = losing parties' stakes (their own deposits, see PartyCost) + protocol subsidy
+//         + appeal bonds raised for the losing sides (the winning side's bond goes back to its contributors)
+//  each coherent voter gets floor(pool / leader_votes)
+//  the winning party gets their own stake back plus pool % leader_votes (the dust)
+//If nobody voted for the winner (min_votes = 0), the whole pool goes to the winning party.
+//Voter stakes never leave the court stake vault, they are settled on the juror ledger instead:
+//  each coherent voter is credited floor(committed voters' stakes / leader_votes) - incoherent voters and
+//  voters who never revealed forfeit theirs,
+//  the winning party is credited the remainder as free juror stake (all of it if nobody voted for them).
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Payout {
+    pub rep: u64,
+    pub pay: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct PayoutCalculator {
+    pub winner_has_case: bool,  // a winner can only be voted for through their case
+    pub winner_cost: PartyCost, // what the winning party deposited
+    pub case_stakes: PartyCost, // deposited by every party with a case, winner included
+    pub losing_bonds: u64,      // appeal bonds received for every side but the winner's
+    pub voter_rep_cost: u64,
+    pub protocol_rep: u64,
+    pub protocol_pay: u64,
+    pub committed_votes: u64, // every vote committed, revealed or not
+    pub leader_votes: u64,
+}
+
+impl PayoutCalculator {
+    pub fn new(dispute: &Dispute) -> Self {
+        let (winner_has_case, winner_cost, winner_index) = match dispute.status {
+            DisputeStatus::Concluded { winner: Some(x) } => (dispute.has_case(x), dispute.party_cost(x).unwrap_or_default(), dispute.party_index(x)),
+            _ => (false, PartyCost::default(), None),
+        };
+        let losing_bonds = dispute.appeal_bonds.iter()
+            .enumerate()
+            .filter(|&(i, _)| Some(i) != winner_index)
+            .map(|(_, &x)| x)
+            .sum();
+
+        PayoutCalculator {
+            winner_has_case,
+            winner_cost,
+            case_stakes: dispute.case_stakes,
+            losing_bonds,
+            voter_rep_cost: dispute.config.voter_rep_cost,
+            protocol_rep: dispute.config.protocol_rep,
+            protocol_pay: dispute.config.protocol_pay,
+            committed_votes: dispute.committed_votes,
+            leader_votes: dispute.leader_votes(),
+        }
+    }
+
+    fn rep_pool(&self) -> Result<u64> {
+        require!(self.winner_has_case, InputError::NoWinningCase);
+        let losing_stakes = self.case_stakes.rep.checked_sub(self.winner_cost.rep).ok_or(InputError::NoWinningCase)?;
+
+        losing_stakes.checked_add(self.protocol_rep)
+            .ok_or_else(|| error!(InputError::PayoutOverflow))
+    }
+
+    fn pay_pool(&self) -> Result<u64> {
+        require!(self.winner_has_case, InputError::NoWinningCase);
+        let losing_stakes = self.case_stakes.pay.checked_sub(self.winner_cost.pay).ok_or(InputError::NoWinningCase)?;
+
+        losing_stakes.checked_add(self.protocol_pay)
+            .and_then(|x| x.checked_add(self.losing_bonds))
+            .ok_or_else(|| error!(InputError::PayoutOverflow))
+    }
+
+    //(share per coherent voter, remainder for the winning party)
+    fn split(&self, pool: u64) -> (u64, u64) {
+        match (pool.checked_div(self.leader_votes), pool.checked_rem(self.leader_votes)) {
+            (Some(share), Some(dust)) => (share, dust),
+            _ => (0, pool),
+        }
+    }
+
+    pub fn winning_party(&self) -> Result<Payout> {
+        let (_, rep_dust) = self.split(self.rep_pool()?);
+        let (_, pay_dust) = self.split(self.pay_pool()?);
+
+        Ok(Payout {
+            rep: self.winner_cost.rep.checked_add(rep_dust).ok_or(InputError::PayoutOverflow)?,
+            pay: self.winner_cost.pay.checked_add(pay_dust).ok_or(InputError::PayoutOverflow)?,
+        })
+    }
+
+    pub fn winning_voter(&self) -> Result<Payout> {
+        let (rep, _) = self.split(self.rep_pool()?);
+        let (pay, _) = self.split(self.pay_pool()?);
+
+        Ok(Payout { rep, pay })
+    }
+
+    fn stake_pool(&self) -> Result<u64> {
+        self.committed_votes.checked_mul(self.voter_rep_cost)
+            .ok_or_else(|| error!(InputError::PayoutOverflow))
+    }
+
+    //juror stake credited to each coherent voter, their own locked stake included
+    pub fn winning_voter_stake(&self) -> Result<u64> {
+        let (share, _) = self.split(self.stake_pool()?);
+        Ok(share)
+    }
+
+    //juror stake credited to the winning party - the dust of the voter stake split
+    pub fn winning_party_stake(&self) -> Result<u64> {
+        let (_, dust) = self.split(self.stake_pool()?);
+        Ok(dust)
+    }
+
+    //no winner - everyone gets exactly their stake back
+    pub fn refund_party(&self, cost: PartyCost) -> Payout {
+        Payout { rep: cost.rep, pay: cost.pay }
+    }
+
+    //juror stake every voter has locked in this dispute
+    pub fn voter_stake(&self) -> u64 {
+        self.voter_rep_cost
+    }
+}
+
+//Appeal fund maths. When the appeal opens, the bond of every fully funded side is moved onto the appeal
+//dispute (see initialize_dispute) - partial contributions to the other sides stay in the fund.
+//  winning side contributors get their contribution back in full, from wherever it is held, plus
+//  contribution * bonus_bps / 10_000 - the bonuses capped by what the losing sides left in the fund
+//  losing side contributors split what the bonuses left of their unspent contributions, pro rata
+//  (a losing side's spent bond paid the appeal's voters, see PayoutCalculator)
+//  without a winner every contributor gets their contribution back
+#[derive(Debug, Clone)]
+pub struct AppealCalculator {
+    pub winning_total: u64,  // raised by the winning side
+    pub unspent_losing: u64, // raised by losing sides that never reached the bond
+    pub bonus_bps: u16,
+}
+
+impl AppealCalculator {
+    pub const BPS: u128 = 10_000;
+
+    //bonuses owed to the winning side, paid out of the losing sides' unspent contributions
+    fn bonus_pool(&self) -> u64 {
+        let uncapped = self.winning_total as u128 * self.bonus_bps as u128 / Self::BPS;
+        uncapped.min(self.unspent_losing as u128) as u64
+    }
+
+    //amount * numerator / denominator, rounded down
+    pub fn pro_rata(amount: u64, numerator: u64, denominator: u64) -> u64 {
+        match (amount as u128 * numerator as u128).checked_div(denominator as u128) {
+            Some(x) => x as u64,
+            None => 0,
+        }
+    }
+
+    //on top of the contribution itself
+    pub fn winning_bonus(&self, amount: u64) -> u64 {
+        Self::pro_rata(amount, self.bonus_pool(), self.winning_total)
+    }
+
+    //only for contributions still in the fund - a spent losing bond has nothing left to return
+    pub fn losing_contributor(&self, amount: u64) -> u64 {
+        Self::pro_rata(amount, self.unspent_losing - self.bonus_pool(), self.unspent_losing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // two parties with a case, claimant posted the larger bond and won
+    fn calculator(committed_votes: u64, leader_votes: u64) -> PayoutCalculator {
+        PayoutCalculator {
+            winner_has_case: true,
+            winner_cost: PartyCost { rep: 15, pay: 7 },
+            case_stakes: PartyCost { rep: 15 + 10, pay: 7 + 5 },
+            losing_bonds: 0,
+            voter_rep_cost: 4,
+            protocol_rep: 5,
+            protocol_pay: 3,
+            committed_votes,
+            leader_votes,
+        }
+    }
+
+    #[test]
+    fn remainder_goes_to_winning_party() {
+        // rep pool = 10 + 5 = 15, pay pool = 5 + 3 = 8, split 3 ways
+        let calc = calculator(3, 3);
+
+        assert_eq!(calc.winning_voter().unwrap(), Payout { rep: 5, pay: 2 });
+        assert_eq!(calc.winning_party().unwrap(), Payout { rep: 15, pay: 7 + 2 });
+    }
+
+    #[test]
+    fn no_coherent_voters_pays_winning_party() {
+        let calc = calculator(0, 0);
+
+        assert_eq!(calc.winning_voter().unwrap(), Payout::default());
+        assert_eq!(calc.winning_party().unwrap(), Payout { rep: 15 + 15, pay: 7 + 8 });
+        assert_eq!(calc.winning_voter_stake().unwrap(), 0);
+        assert_eq!(calc.winning_party_stake().unwrap(), 0);
+    }
+
+    #[test]
+    fn voter_stakes_split_between_coherent_voters() {
+        // 5 voters locked 4 each, 2 sided with the winner
+        let calc = calculator(5, 2);
+
+        assert_eq!(calc.winning_voter_stake().unwrap(), 10);
+        assert_eq!(calc.winning_party_stake().unwrap(), 0);
+    }
+
+    #[test]
+    fn non_revealer_stake_goes_to_coherent_voters() {
+        // 3 voters locked 4 each, 1 revealed for the winner and 2 never revealed
+        let calc = calculator(3, 1);
+
+        assert_eq!(calc.winning_voter_stake().unwrap(), 12);
+        assert_eq!(calc.winning_party_stake().unwrap(), 0);
+    }
+
+    #[test]
+    fn voter_stake_dust_goes_to_winning_party() {
+        // 5 voters locked 4 each, 3 sided with the winner
+        let calc = calculator(5, 3);
+
+        assert_eq!(calc.winning_voter_stake().unwrap(), 6);
+        assert_eq!(calc.winning_party_stake().unwrap(), 2);
+    }
+
+    #[test]
+    fn winner_without_case_is_an_error() {
+        // caught even when the winner staked nothing, so their cost can't tell
+        let mut calc = calculator(1, 1);
+        calc.winner_has_case = false;
+        calc.winner_cost = PartyCost::default();
+
+        assert!(calc.winning_voter().is_err());
+        assert!(calc.winning_party().is_err());
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        let mut calc = calculator(1, 1);
+        calc.protocol_rep = u64::MAX;
+
+        assert!(calc.winning_voter().is_err());
+    }
+
+    #[test]
+    fn losing_appeal_bond_pays_voters() {
+        // losing side's bond of 9 joins the pay pool: 5 + 3 + 9 = 17, split 3 ways
+        let mut calc = calculator(3, 3);
+        calc.losing_bonds = 9;
+
+        assert_eq!(calc.winning_voter().unwrap(), Payout { rep: 5, pay: 5 });
+        assert_eq!(calc.winning_party().unwrap(), Payout { rep: 15, pay: 7 + 2 });
+    }
+
+    #[test]
+    fn appeal_bonus_is_capped_by_unspent_losing_contributions() {
+        let calc = AppealCalculator { winning_total: 1_000, unspent_losing: 50, bonus_bps: 2_000 };
+
+        // 20% of 1_000 would be 200, only 50 is left in the fund
+        assert_eq!(calc.winning_bonus(400), 20);
+        assert_eq!(calc.losing_contributor(50), 0);
+    }
+
+    #[test]
+    fn appeal_leftover_goes_back_to_losing_contributors() {
+        let calc = AppealCalculator { winning_total: 1_000, unspent_losing: 600, bonus_bps: 1_000 };
+
+        assert_eq!(calc.winning_bonus(1_000), 100);
+        assert_eq!(calc.losing_contributor(300), 250);
+    }
+
+    #[test]
+    fn winning_contributors_are_made_whole() {
+        // both sides raised the full bond, so both bonds were spent on the appeal and nothing is left
+        // in the fund - the winning bond comes back off the appeal dispute, the losing one paid its voters
+        let calc = AppealCalculator { winning_total: 1_000, unspent_losing: 0, bonus_bps: 1_000 };
+        let principal = AppealCalculator::pro_rata(1_000, 1_000, 1_000);
+
+        assert_eq!(principal + calc.winning_bonus(1_000), 1_000);
+        assert_eq!(calc.losing_contributor(1_000), 0);
+    }
+
+    //xorshift - keeps the property test deterministic and dependency free
+    fn next(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    #[test]
+    fn payouts_conserve_vault_contents() {
+        let mut seed = 0x2545_f491_4f6c_dd1d;
+
+        for _ in 0..10_000 {
+            let cases = 1 + next(&mut seed) % 8;
+            let costs: Vec<PartyCost> = (0..cases)
+                .map(|_| PartyCost {
+                    rep: next(&mut seed) % 1_000_000_000_000,
+                    pay: next(&mut seed) % 1_000_000_000_000,
+                })
+                .collect();
+            let winner = costs[(next(&mut seed) % cases) as usize];
+
+            let committed_votes = next(&mut seed) % 200;
+            let leader_votes = if committed_votes == 0 { 0 } else { 1 + next(&mut seed) % committed_votes };
+
+            let calc = PayoutCalculator {
+                winner_has_case: true,
+                winner_cost: winner,
+                case_stakes: PartyCost {
+                    rep: costs.iter().map(|c| c.rep).sum(),
+                    pay: costs.iter().map(|c| c.pay).sum(),
+                },
+                losing_bonds: next(&mut seed) % 1_000_000_000_000,
+                voter_rep_cost: next(&mut seed) % 1_000_000_000_000,
+                protocol_rep: next(&mut seed) % 1_000_000_000_000,
+                protocol_pay: next(&mut seed) % 1_000_000_000_000,
+                committed_votes,
+                leader_votes,
+            };
+
+            // deposited into the dispute vaults by parties with a case, the protocol and losing appeal sides
+            let rep_in = calc.case_stakes.rep + calc.protocol_rep;
+            let pay_in = calc.case_stakes.pay + calc.protocol_pay + calc.losing_bonds;
+
+            let party = calc.winning_party().unwrap();
+            let voter = calc.winning_voter().unwrap();
+
+            assert_eq!(party.rep + leader_votes * voter.rep, rep_in);
+            assert_eq!(party.pay + leader_votes * voter.pay, pay_in);
+
+            // locked in the stake vault by every voter, revealed or not
+            let stake_in = committed_votes * calc.voter_rep_cost;
+
+            assert_eq!(calc.winning_party_stake().unwrap() + leader_votes * calc.winning_voter_stake().unwrap(), stake_in);
+        }
+    }
+
+    #[test]
+    fn appeal_payouts_never_exceed_fund() {
+        let mut seed = 0x9e37_79b9_7f4a_7c15;
+
+        for _ in 0..10_000 {
+            let winners: Vec<u64> = (0..1 + next(&mut seed) % 6).map(|_| next(&mut seed) % 1_000_000_000_000).collect();
+            let losers: Vec<u64> = (0..1 + next(&mut seed) % 6).map(|_| next(&mut seed) % 1_000_000_000_000).collect();
+
+            let calc = AppealCalculator {
+                winning_total: winners.iter().sum(),
+                unspent_losing: losers.iter().sum(),
+                bonus_bps: (next(&mut seed) % 20_000) as u16,
+            };
+
+            // the fund pays the bonuses and the losing leftovers out of the unspent losing contributions
+            let bonuses: u64 = winners.iter().map(|&x| calc.winning_bonus(x)).sum();
+            let leftovers: u64 = losers.iter().map(|&x| calc.losing_contributor(x)).sum();
+
+            assert!(bonuses + leftovers <= calc.unspent_losing);
+        }
+    }
+}
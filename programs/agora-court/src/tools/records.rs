@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::{error::InputError, state::{Dispute, DisputeStatus, VoterRecord}};
+
+//The claim queue only holds end times, not the dispute status. An entry past its end time
+//whose dispute was never closed can't be claimed yet, so it shouldn't block new votes / cases.
+//Callers prove that by passing those dispute accounts as remaining accounts.
+
+//ids of the record's ended entries whose dispute account was passed in and is not concluded
+pub fn open_disputes(
+    record: &VoterRecord,
+    court: &Pubkey,
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+) -> Result<Vec<u64>> {
+    let ended = record.ended_disputes();
+    let mut open = Vec::new();
+
+    for info in accounts.iter().filter(|x| x.owner == program_id) {
+        let data = info.try_borrow_data()?;
+        let dispute = match Dispute::try_deserialize(&mut &data[..]) {
+            Ok(x) => x,
+            Err(_) => continue,
+        };
+
+        if matches!(dispute.status, DisputeStatus::Concluded { .. }) {
+            continue;
+        }
+
+        let id = ended.iter().find(|&&id| {
+            Pubkey::create_program_address(
+                &["dispute".as_bytes(), court.as_ref(), id.to_be_bytes().as_ref(), &[dispute.bump]],
+                program_id
+            ) == Ok(*info.key)
+        });
+
+        if let Some(&id) = id {
+            open.push(id);
+        }
+    }
+
+    Ok(open)
+}
+
+//max_dispute_votes can't shrink below a live record's queue. The court counts its live records
+//(see Court::track_record) but not their queue lengths - that would write the court on every vote - so
+//whoever shrinks the limit passes every live record as [record, owner] pairs in the remaining accounts.
+//Each record is checked to be this court's, and together they must cover court.live_records.
+//Records can't outgrow the limit on their own - select_vote / initialize_case stop at it and
+//resize_record never cuts below the queue.
+pub fn check_queue_limit(
+    court: &Pubkey,
+    live_records: u64,
+    max_dispute_votes: u16,
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+) -> Result<()> {
+    let pairs = accounts.chunks_exact(2);
+    require!(pairs.remainder().is_empty(), InputError::LiveRecordsMismatch);
+
+    let mut seen: Vec<Pubkey> = Vec::new();
+    let mut live = 0;
+    for pair in pairs {
+        let (info, owner) = (&pair[0], &pair[1]);
+        require!(info.owner == program_id, ErrorCode::AccountOwnedByWrongProgram);
+        let data = info.try_borrow_data()?;
+        let record = VoterRecord::try_deserialize(&mut &data[..])?;
+
+        let expected = Pubkey::create_program_address(
+            &["record".as_bytes(), court.as_ref(), owner.key.as_ref(), &[record.bump]],
+            program_id
+        ).map_err(|_| InputError::LiveRecordsMismatch)?;
+        require!(*info.key == expected, InputError::LiveRecordsMismatch);
+        require!(!seen.contains(info.key), InputError::LiveRecordsMismatch);
+        seen.push(*info.key);
+
+        require!(record.claim_queue.len() <= max_dispute_votes as usize, InputError::MaxDisputeBelowLiveQueue);
+        if !record.claim_queue.is_empty() {
+            live += 1;
+        }
+    }
+
+    //records with an empty queue don't count, but every live one has to be among those checked
+    require!(live == live_records, InputError::LiveRecordsMismatch);
+
+    Ok(())
+}
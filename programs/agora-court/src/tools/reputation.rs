@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self as token, Burn, FreezeAccount, MintTo, ThawAccount, TokenAccount};
+
+//Soulbound rep helpers. The court PDA is the mint + freeze authority, so every account
+//it touches is thawed for the operation and frozen again afterwards - frozen accounts
+//cannot transfer, which is what keeps the reputation non-transferable.
+
+fn thaw<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    account: &InterfaceAccount<'info, TokenAccount>,
+    court: &AccountInfo<'info>,
+    court_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if !account.is_frozen() {
+        return Ok(());
+    }
+
+    token::thaw_account(CpiContext::new_with_signer(
+        token_program.clone(),
+        ThawAccount {
+            account: account.to_account_info(),
+            mint: mint.clone(),
+            authority: court.clone(),
+        },
+        court_seeds,
+    ))
+}
+
+fn freeze<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    account: &InterfaceAccount<'info, TokenAccount>,
+    court: &AccountInfo<'info>,
+    court_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    token::freeze_account(CpiContext::new_with_signer(
+        token_program.clone(),
+        FreezeAccount {
+            account: account.to_account_info(),
+            mint: mint.clone(),
+            authority: court.clone(),
+        },
+        court_seeds,
+    ))
+}
+
+pub fn mint_soulbound<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    account: &InterfaceAccount<'info, TokenAccount>,
+    court: &AccountInfo<'info>,
+    court_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    thaw(token_program, mint, account, court, court_seeds)?;
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            MintTo {
+                mint: mint.clone(),
+                to: account.to_account_info(),
+                authority: court.clone(),
+            },
+            court_seeds,
+        ),
+        amount,
+    )?;
+
+    freeze(token_program, mint, account, court, court_seeds)
+}
+
+//burns at most the current balance, so a slash can never fail the claim
+pub fn burn_soulbound<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    account: &InterfaceAccount<'info, TokenAccount>,
+    owner: &AccountInfo<'info>,
+    court: &AccountInfo<'info>,
+    court_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    let amount = amount.min(account.amount);
+    if amount == 0 {
+        return Ok(());
+    }
+
+    thaw(token_program, mint, account, court, court_seeds)?;
+
+    token::burn(
+        CpiContext::new(
+            token_program.clone(),
+            Burn {
+                mint: mint.clone(),
+                from: account.to_account_info(),
+                authority: owner.clone(),
+            },
+        ),
+        amount,
+    )?;
+
+    freeze(token_program, mint, account, court, court_seeds)
+}
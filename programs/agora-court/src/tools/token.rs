@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::token_2022::{
+    Token2022,
+    spl_token_2022::{
+        extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+        state::{Account as AccountState, Mint as MintState},
+    },
+};
+use anchor_spl::associated_token::{self, get_associated_token_address_with_program_id, Create};
+use anchor_spl::token_interface::{self, InitializeAccount3, Mint, TokenAccount, TransferChecked};
+use crate::error::InputError;
+
+//Token program agnostic transfers (spl-token + Token-2022).
+//Deposits are grossed up by the mint's transfer fee so the vault always receives exactly
+//the configured amount - payout maths can then keep working off the dispute config.
+//Withdrawals send the configured amount and the recipient bears any outbound fee.
+
+//amount the sender must transfer so that `amount` arrives after the transfer fee
+pub fn gross_amount(mint: &InterfaceAccount<Mint>, amount: u64) -> Result<u64> {
+    let info = mint.to_account_info();
+    let data = info.try_borrow_data()?;
+    let state = StateWithExtensions::<MintState>::unpack(&data)?;
+
+    match state.get_extension::<TransferFeeConfig>() {
+        Ok(config) => {
+            let epoch = Clock::get()?.epoch;
+            config.calculate_inverse_epoch_fee(epoch, amount)
+                .and_then(|fee| amount.checked_add(fee))
+                .ok_or_else(|| error!(InputError::PayoutOverflow))
+        },
+        Err(_) => Ok(amount),
+    }
+}
+
+pub fn deposit<'info>(
+    token_program: &AccountInfo<'info>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    vault: &mut InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    authority: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let before = vault.amount;
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            token_program.clone(),
+            TransferChecked {
+                from: from.to_account_info(),
+                mint: mint.to_account_info(),
+                to: vault.to_account_info(),
+                authority: authority.clone(),
+            },
+        ),
+        gross_amount(mint, amount)?,
+        mint.decimals,
+    )?;
+
+    //vault accounting is only sound if what arrived is what the config says
+    vault.reload()?;
+    let received = vault.amount.checked_sub(before).ok_or(InputError::TransferAmountMismatch)?;
+    require!(received >= amount, InputError::TransferAmountMismatch);
+
+    Ok(())
+}
+
+pub fn withdraw<'info>(
+    token_program: &AccountInfo<'info>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    authority: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: vault.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        mint.decimals,
+    )
+}
+
+//the owner's ATA under the given token program, created (paid by payer) if it doesn't exist yet
+pub fn owner_ata<'info>(
+    payer: &AccountInfo<'info>,
+    ata: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    system_program: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    associated_token_program: &AccountInfo<'info>,
+) -> Result<InterfaceAccount<'info, TokenAccount>> {
+    let expected = get_associated_token_address_with_program_id(owner.key, &mint.key(), token_program.key);
+    require!(*ata.key == expected, InputError::OwnerAtaMismatch);
+
+    associated_token::create_idempotent(CpiContext::new(
+        associated_token_program.clone(),
+        Create { // create_idempotent takes the same accounts
+            payer: payer.clone(),
+            associated_token: ata.clone(),
+            authority: owner.clone(),
+            mint: mint.to_account_info(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+        },
+    ))?;
+
+    InterfaceAccount::try_from(ata)
+}
+
+//creates a vault at a program PDA under the mint's own token program
+//anchor's `init` always goes through the one `token_program` account, which can't serve a court
+//whose rep and pay mints live under different token programs
+pub fn init_vault<'info>(
+    payer: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    authority: &AccountInfo<'info>,
+    vault_seeds: &[&[&[u8]]],
+    system_program: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+) -> Result<InterfaceAccount<'info, TokenAccount>> {
+    let mint_info = mint.to_account_info();
+    require!(mint_info.owner == token_program.key, InputError::TokenProgramMismatch);
+
+    //same sizing as anchor's init - Token-2022 accounts carry the extensions their mint requires
+    let space = if *mint_info.owner == Token2022::id() {
+        let data = mint_info.try_borrow_data()?;
+        let state = StateWithExtensions::<MintState>::unpack(&data)?;
+        let required = ExtensionType::get_required_init_account_extensions(&state.get_extension_types()?);
+        ExtensionType::get_account_len::<AccountState>(&required)
+    } else {
+        anchor_spl::token::TokenAccount::LEN
+    };
+    let rent = Rent::get()?.minimum_balance(space);
+
+    //a pre-funded PDA can't go through create_account, so top it up and allocate/assign instead
+    let lamports = vault.lamports();
+    if lamports == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount { from: payer.clone(), to: vault.clone() },
+                vault_seeds,
+            ),
+            rent,
+            space as u64,
+            token_program.key,
+        )?;
+    } else {
+        if rent > lamports {
+            system_program::transfer(
+                CpiContext::new(system_program.clone(), Transfer { from: payer.clone(), to: vault.clone() }),
+                rent - lamports,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(system_program.clone(), Allocate { account_to_allocate: vault.clone() }, vault_seeds),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(system_program.clone(), Assign { account_to_assign: vault.clone() }, vault_seeds),
+            token_program.key,
+        )?;
+    }
+
+    token_interface::initialize_account3(CpiContext::new(
+        token_program.clone(),
+        InitializeAccount3 {
+            account: vault.clone(),
+            mint: mint_info,
+            authority: authority.clone(),
+        },
+    ))?;
+
+    InterfaceAccount::try_from(vault)
+}
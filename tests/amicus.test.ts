@@ -0,0 +1,192 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { maxDisputeVotes, decimals, courtName, amicusBond, amicusSpamThresholdBps, amicusDisputeOptions as disputeOptions } from './config';
+import { CourtSuite, DisputeConfig } from './court-suite';
+import { UserSuite } from './user-suite';
+
+// waits until the given unix timestamp (plus a buffer) has passed on the validator
+const waitUntil = async (timestamp: anchor.BN, buffer: number = 3) => {
+    let curTime = Math.floor(Date.now() / 1000);
+    if (curTime < timestamp.toNumber()) {
+        await new Promise<void>((resolve) => setTimeout(resolve, (timestamp.toNumber() - curTime + buffer) * 1000));
+    }
+}
+
+describe('agora-court-amicus', () => {
+    //find the provider and set the anchor provider
+    let cs = new CourtSuite();
+    let partyOne = new UserSuite();
+    let partyTwo = new UserSuite();
+    let voterOne = new UserSuite();
+    let voterTwo = new UserSuite();
+    let spammer = new UserSuite();
+    let friend = new UserSuite(); // honest amicus, flagged by one juror only
+    let disputeConfig: DisputeConfig;
+
+    const expectFailure = async (call: () => Promise<any>, code: string) => {
+        let failed = false;
+        try {
+            await call();
+        } catch (err) {
+            failed = true;
+            expect(err.error.errorCode.code).to.equal(code);
+        }
+        expect(failed).to.be.true;
+    }
+
+    const flagOf = (voter: UserSuite, submitter: UserSuite) => {
+        return voter.getAmicusFlag(voter.getAmicusSubmission(cs.disputeID, submitter.user.publicKey));
+    }
+
+    describe('setup!', () => {
+        it('setup_court_and_dispute!', async () => {
+            await cs.setAccounts(decimals, courtName);
+            await cs.initCourt(maxDisputeVotes);
+            await cs.setAmicusConfig(amicusBond, amicusSpamThresholdBps);
+
+            let courtConfig = cs.getCourtConfig();
+            for (const user of [partyOne, partyTwo, voterOne, voterTwo, spammer, friend]) {
+                await user.setAccounts(courtConfig);
+                await user.initRecord();
+            }
+
+            disputeConfig = await cs.initDispute(disputeOptions);
+        });
+
+        it('parties_interact!', async () => {
+            for (const party of [partyOne, partyTwo]) {
+                let ata = await party.getOrCreateRepATA(party.user.publicKey, false);
+                await cs.mintRepTokens(ata.address, disputeOptions.partyRepCost.toNumber());
+                await party.interact(cs.disputeID);
+            }
+        });
+    });
+
+    describe('submissions!', () => {
+        it('amicus_deposits_bond!', async () => {
+            for (const amicus of [spammer, friend]) {
+                let before = await cs.getSolBalance(amicus.user.publicKey);
+                await amicus.submitAmicusEvidence(cs.disputeID, "https://example.com/brief");
+
+                let submission = amicus.getAmicusSubmission(cs.disputeID);
+                let submissionState = await cs.program.account.amicusSubmission.fetch(submission);
+                expect(submissionState.bond.eq(amicusBond)).to.be.true;
+                expect(submissionState.spamFlags).to.equal(0);
+
+                // the bond sits on the submission on top of its rent
+                let rent = await cs.connection.getMinimumBalanceForRentExemption((await cs.connection.getAccountInfo(submission)).data.length);
+                expect(await cs.getSolBalance(submission)).to.equal(rent + amicusBond.toNumber());
+                expect(before - await cs.getSolBalance(amicus.user.publicKey)).to.be.gte(rent + amicusBond.toNumber());
+            }
+        });
+
+        it('party_cannot_submit_amicus!', async () => {
+            await expectFailure(() => partyOne.submitAmicusEvidence(cs.disputeID, "https://example.com/self"), "AmicusIsParty");
+        });
+
+        it('parties_submit_cases!', async () => {
+            await partyOne.initCase(cs.disputeID, "I'm right guys trust");
+            await partyTwo.initCase(cs.disputeID, "Don't trust that bozo");
+        });
+    });
+
+    describe('flags!', () => {
+        it('voters_commit!', async () => {
+            for (const voter of [voterOne, voterTwo]) {
+                let ata = await voter.getOrCreateRepATA(voter.user.publicKey, false);
+                await cs.mintRepTokens(ata.address, disputeOptions.voterRepRequired.toNumber());
+                await voter.depositStake(disputeOptions.voterRepRequired);
+                await voter.selectVote(cs.disputeID, partyTwo.user.publicKey);
+            }
+        });
+
+        it('unrevealed_juror_cannot_flag!', async () => {
+            await expectFailure(() => voterOne.flagAmicusEvidence(cs.disputeID, spammer.user.publicKey), "AmicusFlagNotJuror");
+        });
+
+        it('revealed_jurors_flag!', async () => {
+            await waitUntil(disputeConfig.votingEndsAt, 5);
+            for (const voter of [voterOne, voterTwo]) {
+                await voter.revealVote(cs.disputeID);
+                await voter.flagAmicusEvidence(cs.disputeID, spammer.user.publicKey);
+            }
+            await voterOne.flagAmicusEvidence(cs.disputeID, friend.user.publicKey);
+
+            let spamState = await cs.program.account.amicusSubmission.fetch(spammer.getAmicusSubmission(cs.disputeID));
+            expect(spamState.spamFlags).to.equal(2);
+            expect(spamState.openFlags).to.equal(2);
+
+            let friendState = await cs.program.account.amicusSubmission.fetch(friend.getAmicusSubmission(cs.disputeID));
+            expect(friendState.spamFlags).to.equal(1);
+        });
+
+        it('juror_cannot_flag_twice!', async () => {
+            let failed = false;
+            try {
+                await voterOne.flagAmicusEvidence(cs.disputeID, spammer.user.publicKey);
+            } catch (err) {
+                failed = true;
+            }
+            expect(failed).to.be.true;
+        });
+
+        it('flag_after_reveal_fails!', async () => {
+            await waitUntil(disputeConfig.disputeEndsAt);
+            await expectFailure(() => voterTwo.flagAmicusEvidence(cs.disputeID, friend.user.publicKey), "AmicusFlaggingClosed");
+
+            await cs.closeDispute();
+        });
+    });
+
+    describe('settle!', () => {
+        it('mismatched_flag_fails!', async () => {
+            // voterOne's flag on the spam submission can't close against the friend's
+            await expectFailure(
+                () => cs.settleAmicusBond(friend.user.publicKey, [{ flag: flagOf(voterOne, spammer), juror: voterOne.user.publicKey }]),
+                "AmicusFlagMismatch"
+            );
+        });
+
+        it('spam_bond_forfeits_to_treasury!', async () => {
+            let treasuryBefore = await cs.getSolBalance(cs.getTreasury());
+            let flags = [voterOne, voterTwo].map((voter) => ({ flag: flagOf(voter, spammer), juror: voter.user.publicKey }));
+            let flagRent = await cs.getSolBalance(flags[0].flag);
+            let voterBefore = await cs.getSolBalance(voterOne.user.publicKey);
+
+            await cs.settleAmicusBond(spammer.user.publicKey, flags);
+
+            expect(await cs.getSolBalance(cs.getTreasury())).to.equal(treasuryBefore + amicusBond.toNumber());
+
+            // the submission and both flags are closed, flag rent back to the jurors
+            let spamState = await cs.program.account.amicusSubmission.fetchNullable(spammer.getAmicusSubmission(cs.disputeID));
+            expect(spamState).to.be.null;
+            for (const { flag } of flags) {
+                expect(await cs.connection.getAccountInfo(flag)).to.be.null;
+            }
+            expect(await cs.getSolBalance(voterOne.user.publicKey)).to.equal(voterBefore + flagRent);
+        });
+
+        it('open_flags_hold_the_bond!', async () => {
+            // settling without the friend's flag only closes what was passed - nothing here
+            await cs.settleAmicusBond(friend.user.publicKey);
+
+            let friendState = await cs.program.account.amicusSubmission.fetchNullable(friend.getAmicusSubmission(cs.disputeID));
+            expect(friendState).to.not.be.null;
+            expect(friendState.openFlags).to.equal(1);
+        });
+
+        it('bond_refunded_on_close!', async () => {
+            let submission = friend.getAmicusSubmission(cs.disputeID);
+            let held = await cs.getSolBalance(submission);
+            let friendBefore = await cs.getSolBalance(friend.user.publicKey);
+            let treasuryBefore = await cs.getSolBalance(cs.getTreasury());
+
+            // 1 of 2 revealed jurors is below the 60% threshold
+            await cs.settleAmicusBond(friend.user.publicKey, [{ flag: flagOf(voterOne, friend), juror: voterOne.user.publicKey }]);
+
+            expect(await cs.getSolBalance(friend.user.publicKey)).to.equal(friendBefore + held);
+            expect(await cs.getSolBalance(cs.getTreasury())).to.equal(treasuryBefore);
+            expect(await cs.program.account.amicusSubmission.fetchNullable(submission)).to.be.null;
+        });
+    });
+});
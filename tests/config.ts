@@ -52,4 +52,41 @@ export const multiDisputeOptions: DisputeOptions = {
     minVotes: new anchor.BN(1),
     protocolPay: new anchor.BN(0),
     protocolRep: new anchor.BN(5 * LAMPORTS_PER_MINT)
-}
\ No newline at end of file
+}
+
+// soulbound - rep can't be staked, so it only gates voting
+export const soulboundCoherentReward = new anchor.BN(10 * LAMPORTS_PER_MINT);
+export const soulboundIncoherentSlash = new anchor.BN(4 * LAMPORTS_PER_MINT);
+
+export const soulboundDisputeOptions: DisputeOptions = {
+    users: [null, null],
+    graceDurationSeconds: 0.1*60,
+    initCaseDurationSeconds: 0.18*60,
+    commitDurationSeconds: 0.26*60,
+    revealDurationSeconds: 0.38*60,
+    voterRepRequired: new anchor.BN(0),
+    voterRepCost: new anchor.BN(0),
+    partyRepCost: new anchor.BN(0),
+    partyPayCost: new anchor.BN(0),
+    minVotes: new anchor.BN(1),
+    protocolPay: new anchor.BN(0),
+    protocolRep: new anchor.BN(0)
+}
+// amicus - longer phases, the flow has more users to get through
+export const amicusBond = new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL);
+export const amicusSpamThresholdBps = 6000;
+
+export const amicusDisputeOptions: DisputeOptions = {
+    users: [null, null],
+    graceDurationSeconds: 0.2*60,
+    initCaseDurationSeconds: 0.4*60,
+    commitDurationSeconds: 0.6*60,
+    revealDurationSeconds: 0.8*60,
+    voterRepRequired: new anchor.BN(5 * LAMPORTS_PER_MINT),
+    voterRepCost: new anchor.BN(0),
+    partyRepCost: new anchor.BN(15 * LAMPORTS_PER_MINT),
+    partyPayCost: new anchor.BN(0),
+    minVotes: new anchor.BN(1),
+    protocolPay: new anchor.BN(0),
+    protocolRep: new anchor.BN(5 * LAMPORTS_PER_MINT)
+}
@@ -48,6 +48,54 @@ export interface CourtConfig {
     repMint: PublicKey
 }
 
+export interface DisputeDefaults {
+    graceDuration: anchor.BN,
+    initCasesDuration: anchor.BN,
+    votingDuration: anchor.BN,
+    revealDuration: anchor.BN,
+    voterRepRequired: anchor.BN,
+    voterRepCost: anchor.BN,
+    repCost: anchor.BN,
+    payCost: anchor.BN,
+    minVotes: anchor.BN,
+    protocolPay: anchor.BN,
+    protocolRep: anchor.BN
+}
+
+export interface DisputeOverrides {
+    graceDuration?: anchor.BN | null,
+    initCasesDuration?: anchor.BN | null,
+    votingDuration?: anchor.BN | null,
+    revealDuration?: anchor.BN | null,
+    voterRepRequired?: anchor.BN | null,
+    voterRepCost?: anchor.BN | null,
+    repCost?: anchor.BN | null,
+    payCost?: anchor.BN | null,
+    minVotes?: anchor.BN | null,
+    protocolPay?: anchor.BN | null,
+    protocolRep?: anchor.BN | null
+}
+
+const noOverrides: DisputeOverrides = {
+    graceDuration: null,
+    initCasesDuration: null,
+    votingDuration: null,
+    revealDuration: null,
+    voterRepRequired: null,
+    voterRepCost: null,
+    repCost: null,
+    payCost: null,
+    minVotes: null,
+    protocolPay: null,
+    protocolRep: null
+}
+
+export interface TemplatePolicy {
+    bounds: object | null,
+    allowPartyCosts: boolean,
+    allowOpenSlots: boolean
+}
+
 //specific to the court and its ix, contains court owner, etc
 export class CourtSuite {
     // provider and program
@@ -64,6 +112,8 @@ export class CourtSuite {
 
     // info
     decimals: number;
+    repMintKey: PublicKey; //repMint keypair, or the court-owned mint pda for soulbound courts
+    soulbound: boolean;
     courtName: string;
     disputeID: anchor.BN;
 
@@ -100,6 +150,8 @@ export class CourtSuite {
         //set court info
         this.decimals = decimals;
         this.courtName = courtName;
+        this.repMintKey = this.repMint.publicKey;
+        this.soulbound = false;
 
         //airdrop 2 sol to editAuth
         await this.requestAirdrop(this.editAuthority.publicKey, 1);
@@ -150,7 +202,7 @@ export class CourtSuite {
         await mintToChecked(
             this.connection,
             this.editAuthority,
-            this.repMint.publicKey,
+            this.repMintKey,
             ata,
             this.mintAuthority,
             amount,
@@ -164,7 +216,7 @@ export class CourtSuite {
 
     getRepATA = (owner: PublicKey, isPDA: boolean = true) => {
         return getAssociatedTokenAddressSync(
-            this.repMint.publicKey,
+            this.repMintKey,
             owner,
             isPDA,
             TOKEN_PROGRAM_ID,
@@ -176,11 +228,15 @@ export class CourtSuite {
         let config: CourtConfig = {
             court: this.court.publicKey,
             courtName: this.courtName,
-            repMint: this.repMint.publicKey
+            repMint: this.repMintKey
         };
         return config;
     }
 
+    getRepVault = (dispute: PublicKey) => {
+        return this.findProgramAddress("rep_vault", [dispute]).publicKey;
+    }
+
     getSolBalance = async (pubkey: PublicKey) => {
         return this.provider.connection
           .getBalance(pubkey)
@@ -223,12 +279,13 @@ export class CourtSuite {
 
     // --- INSTRUCTIONS --- //
 
-    initCourt = async (maxVotes: number) => {
+    initCourt = async (maxVotes: number, unbondingPeriod: number = 0) => {
         try {
             await this.program.methods
                 .initializeCourt(
                     this.courtName,
-                    maxVotes
+                    maxVotes,
+                    new anchor.BN(unbondingPeriod)
                 )
                 .accounts({
                     court: this.court.publicKey,
@@ -248,27 +305,63 @@ export class CourtSuite {
         }
     }
 
-    initDispute = async (config: DisputeOptions) => {
+    initSoulboundCourt = async (maxVotes: number, coherentReward: anchor.BN, incoherentSlash: anchor.BN) => {
+        //the court creates and owns the rep mint
+        this.repMintKey = this.findProgramAddress("rep_mint", [this.court.publicKey]).publicKey;
+        this.soulbound = true;
+
+        try {
+            await this.program.methods
+                .initializeSoulboundCourt(
+                    this.courtName,
+                    maxVotes,
+                    this.decimals,
+                    coherentReward,
+                    incoherentSlash
+                )
+                .accounts({
+                    court: this.court.publicKey,
+                    repMint: this.repMintKey,
+                    authority: this.editAuthority.publicKey,
+                    protocol: this.protocol.publicKey,
+                    payMint: this.program.programId, //NULL
+                    systemProgram: SystemProgram.programId,
+                    tokenProgram: TOKEN_PROGRAM_ID
+                })
+                .signers([
+                    this.editAuthority
+                ])
+                .rpc()
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    initDispute = async (config: DisputeOptions, protocol: Keypair = this.protocol, protocolEntry: PublicKey = null) => {
         let courtState = await this.program.account.court.fetch(this.court.publicKey);
         this.dispute = this.findProgramAddress("dispute", [this.court.publicKey, courtState.numDisputes]);
 
         let protocolRepAta = await getOrCreateAssociatedTokenAccount(
             this.connection,
             this.editAuthority,
-            this.repMint.publicKey,
-            this.protocol.publicKey,
+            this.repMintKey,
+            protocol.publicKey,
             true
         );
 
-        try {
-            await this.mintRepTokens(protocolRepAta.address, config.protocolRep.toNumber());
-        } catch (err) {
-            console.log(err);
-            throw err;
+        //soulbound rep can only be minted by the court
+        if (!this.soulbound) {
+            try {
+                await this.mintRepTokens(protocolRepAta.address, config.protocolRep.toNumber());
+            } catch (err) {
+                console.log(err);
+                throw err;
+            }
         }
 
         let curTime = Math.floor(Date.now() / 1000);
-        let repVault = this.getRepATA(this.dispute.publicKey);
+        let repVault = this.getRepVault(this.dispute.publicKey);
 
         let disputeConfig: DisputeConfig = {
             graceEndsAt: new anchor.BN(curTime + config.graceDurationSeconds),
@@ -289,7 +382,9 @@ export class CourtSuite {
                 .initializeDispute(
                     this.courtName,
                     config.users,
-                    disputeConfig
+                    disputeConfig,
+                    [], // every party pays the config defaults
+                    null // no template overrides
                 )
                 .accounts({
                     dispute: this.dispute.publicKey,
@@ -297,16 +392,21 @@ export class CourtSuite {
                     payVault: this.program.programId, //NULL
                     court: this.court.publicKey,
                     payer: this.editAuthority.publicKey,
-                    protocol: this.protocol.publicKey,
+                    protocol: protocol.publicKey,
+                    protocolEntry: protocolEntry ?? this.program.programId, //NULL unless a registered protocol
+                    disputeTemplate: this.program.programId, //NULL
                     protocolRepAta: protocolRepAta.address,
                     protocolPayAta: this.program.programId, //NULL
-                    repMint: this.repMint.publicKey,
+                    repMint: this.repMintKey,
                     payMint: this.program.programId, //NULL
+                    appealFund: this.program.programId, //NULL
+                    appealedDispute: this.program.programId, //NULL
+                    appealVault: this.program.programId, //NULL
                     systemProgram: SystemProgram.programId,
-                    tokenProgram: TOKEN_PROGRAM_ID,
-                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID
+                    repTokenProgram: TOKEN_PROGRAM_ID,
+                    payTokenProgram: TOKEN_PROGRAM_ID
                 })
-                .signers([this.editAuthority, this.protocol])
+                .signers([this.editAuthority, protocol])
                 .rpc()
         } catch (err) {
             console.log(err);
@@ -336,4 +436,487 @@ export class CourtSuite {
             throw err;
         }
     }
+
+    setPaused = async (paused: boolean) => {
+        try {
+            await this.program.methods
+                .setPaused(
+                    this.courtName,
+                    paused
+                )
+                .accounts({
+                    court: this.court.publicKey,
+                    authority: this.editAuthority.publicKey,
+                })
+                .signers([this.editAuthority])
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    windDown = async () => {
+        try {
+            await this.program.methods
+                .windDown(
+                    this.courtName
+                )
+                .accounts({
+                    court: this.court.publicKey,
+                    authority: this.editAuthority.publicKey,
+                })
+                .signers([this.editAuthority])
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    windDownDispute = async () => {
+        try {
+            await this.program.methods
+                .windDownDispute(
+                    this.courtName,
+                    this.disputeID
+                )
+                .accounts({
+                    dispute: this.dispute.publicKey,
+                    court: this.court.publicKey,
+                })
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    refundProtocol = async () => {
+        try {
+            await this.program.methods
+                .refundProtocol(
+                    this.courtName,
+                    this.disputeID
+                )
+                .accounts({
+                    dispute: this.dispute.publicKey,
+                    repVault: this.getRepVault(this.dispute.publicKey),
+                    payVault: this.program.programId, //NULL
+                    court: this.court.publicKey,
+                    protocol: this.protocol.publicKey,
+                    protocolRepAta: this.getRepATA(this.protocol.publicKey),
+                    protocolPayAta: this.program.programId, //NULL
+                    repMint: this.repMintKey,
+                    payMint: this.program.programId, //NULL
+                    repTokenProgram: TOKEN_PROGRAM_ID,
+                    payTokenProgram: TOKEN_PROGRAM_ID
+                })
+                .signers([this.protocol])
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    getProtocolEntry = (protocol: PublicKey) => {
+        return this.findProgramAddress("protocol", [this.court.publicKey, protocol]).publicKey;
+    }
+
+    addProtocol = async (protocol: PublicKey, rateLimit: { maxDisputes: number, window: anchor.BN } = null, authority: Keypair = this.editAuthority) => {
+        try {
+            await this.program.methods
+                .addProtocol(
+                    this.courtName,
+                    null, // no defaults, disputes pass their own config
+                    rateLimit
+                )
+                .accounts({
+                    protocolEntry: this.getProtocolEntry(protocol),
+                    court: this.court.publicKey,
+                    authority: authority.publicKey,
+                    protocol: protocol,
+                    systemProgram: SystemProgram.programId
+                })
+                .signers([authority])
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    removeProtocol = async (protocol: PublicKey) => {
+        try {
+            await this.program.methods
+                .removeProtocol(
+                    this.courtName
+                )
+                .accounts({
+                    protocolEntry: this.getProtocolEntry(protocol),
+                    court: this.court.publicKey,
+                    authority: this.editAuthority.publicKey,
+                })
+                .signers([this.editAuthority])
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    getTemplate = (name: string) => {
+        return this.findProgramAddress("template", [this.court.publicKey, name]).publicKey;
+    }
+
+    setDisputeTemplate = async (name: string, defaults: DisputeDefaults, overridable: number, policy: TemplatePolicy) => {
+        try {
+            await this.program.methods
+                .setDisputeTemplate(
+                    this.courtName,
+                    name,
+                    defaults,
+                    overridable,
+                    policy
+                )
+                .accounts({
+                    template: this.getTemplate(name),
+                    court: this.court.publicKey,
+                    authority: this.editAuthority.publicKey,
+                    systemProgram: SystemProgram.programId
+                })
+                .signers([this.editAuthority])
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    // like initDispute, but the config is resolved from a court template
+    initTemplateDispute = async (name: string, users: (PublicKey | null)[], partyCosts: { rep: anchor.BN, pay: anchor.BN }[] = [], overrides: DisputeOverrides = {}) => {
+        let courtState = await this.program.account.court.fetch(this.court.publicKey);
+        this.dispute = this.findProgramAddress("dispute", [this.court.publicKey, courtState.numDisputes]);
+
+        let protocolRepAta = await getOrCreateAssociatedTokenAccount(
+            this.connection,
+            this.editAuthority,
+            this.repMintKey,
+            this.protocol.publicKey,
+            true
+        );
+
+        try {
+            await this.program.methods
+                .initializeDispute(
+                    this.courtName,
+                    users,
+                    null, // config comes from the template
+                    partyCosts,
+                    { ...noOverrides, ...overrides }
+                )
+                .accounts({
+                    dispute: this.dispute.publicKey,
+                    repVault: this.getRepVault(this.dispute.publicKey),
+                    payVault: this.program.programId, //NULL
+                    court: this.court.publicKey,
+                    payer: this.editAuthority.publicKey,
+                    protocol: this.protocol.publicKey,
+                    protocolEntry: this.program.programId, //NULL
+                    disputeTemplate: this.getTemplate(name),
+                    protocolRepAta: protocolRepAta.address,
+                    protocolPayAta: this.program.programId, //NULL
+                    repMint: this.repMintKey,
+                    payMint: this.program.programId, //NULL
+                    appealFund: this.program.programId, //NULL
+                    appealedDispute: this.program.programId, //NULL
+                    appealVault: this.program.programId, //NULL
+                    systemProgram: SystemProgram.programId,
+                    repTokenProgram: TOKEN_PROGRAM_ID,
+                    payTokenProgram: TOKEN_PROGRAM_ID
+                })
+                .signers([this.editAuthority, this.protocol])
+                .rpc()
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+
+        this.disputeID = courtState.numDisputes;
+    }
+
+    proposeTransfer = async (authority: PublicKey | null, protocol: PublicKey | null, signer: Keypair = this.editAuthority) => {
+        try {
+            await this.program.methods
+                .proposeTransfer(
+                    this.courtName,
+                    authority,
+                    protocol
+                )
+                .accounts({
+                    court: this.court.publicKey,
+                    authority: signer.publicKey,
+                })
+                .signers([signer])
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    cancelTransfer = async () => {
+        try {
+            await this.program.methods
+                .cancelTransfer(
+                    this.courtName
+                )
+                .accounts({
+                    court: this.court.publicKey,
+                    authority: this.editAuthority.publicKey,
+                })
+                .signers([this.editAuthority])
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    acceptAuthority = async (newAuthority: Keypair) => {
+        try {
+            await this.program.methods
+                .acceptAuthority(
+                    this.courtName
+                )
+                .accounts({
+                    court: this.court.publicKey,
+                    newAuthority: newAuthority.publicKey,
+                })
+                .signers([newAuthority])
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    acceptProtocol = async (newProtocol: Keypair) => {
+        try {
+            await this.program.methods
+                .acceptProtocol(
+                    this.courtName
+                )
+                .accounts({
+                    court: this.court.publicKey,
+                    newProtocol: newProtocol.publicKey,
+                })
+                .signers([newProtocol])
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    getCourtChange = () => {
+        return this.findProgramAddress("court_change", [this.court.publicKey]).publicKey;
+    }
+
+    editCourt = async (maxVotes: number, unbondingPeriod: number, crankTip: number, changeDelay: number, repMint: PublicKey = this.repMintKey) => {
+        try {
+            await this.program.methods
+                .editCourt(
+                    this.courtName,
+                    maxVotes,
+                    new anchor.BN(unbondingPeriod),
+                    new anchor.BN(crankTip),
+                    new anchor.BN(changeDelay)
+                )
+                .accounts({
+                    courtChange: this.getCourtChange(),
+                    court: this.court.publicKey,
+                    authority: this.editAuthority.publicKey,
+                    repMint: repMint,
+                    payMint: this.program.programId, //NULL
+                    systemProgram: SystemProgram.programId
+                })
+                .signers([this.editAuthority])
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    applyCourtChange = async () => {
+        try {
+            await this.program.methods
+                .applyCourtChange(
+                    this.courtName
+                )
+                .accounts({
+                    courtChange: this.getCourtChange(),
+                    court: this.court.publicKey,
+                    proposer: this.editAuthority.publicKey,
+                    stakeVault: this.findProgramAddress("stake_vault", [this.court.publicKey]).publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID
+                })
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    cancelCourtChange = async (authority: Keypair = this.editAuthority) => {
+        try {
+            await this.program.methods
+                .cancelCourtChange(
+                    this.courtName
+                )
+                .accounts({
+                    courtChange: this.getCourtChange(),
+                    court: this.court.publicKey,
+                    authority: authority.publicKey,
+                    proposer: this.editAuthority.publicKey
+                })
+                .signers([authority])
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    setJurorAllowlist = async (enabled: boolean) => {
+        try {
+            await this.program.methods
+                .setJurorAllowlist(
+                    this.courtName,
+                    enabled
+                )
+                .accounts({
+                    court: this.court.publicKey,
+                    authority: this.editAuthority.publicKey,
+                })
+                .signers([this.editAuthority])
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    setJurorCollection = async (gate: { collection: PublicKey, replacesRep: boolean } | null) => {
+        try {
+            await this.program.methods
+                .setJurorCollection(
+                    this.courtName,
+                    gate
+                )
+                .accounts({
+                    court: this.court.publicKey,
+                    authority: this.editAuthority.publicKey,
+                })
+                .signers([this.editAuthority])
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    addJuror = async (juror: PublicKey, authority: Keypair = this.editAuthority) => {
+        try {
+            await this.program.methods
+                .addJuror(
+                    this.courtName
+                )
+                .accounts({
+                    jurorPass: this.findProgramAddress("juror", [this.court.publicKey, juror]).publicKey,
+                    court: this.court.publicKey,
+                    authority: authority.publicKey,
+                    juror: juror,
+                    systemProgram: SystemProgram.programId
+                })
+                .signers([authority])
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    removeJuror = async (juror: PublicKey) => {
+        try {
+            await this.program.methods
+                .removeJuror(
+                    this.courtName
+                )
+                .accounts({
+                    jurorPass: this.findProgramAddress("juror", [this.court.publicKey, juror]).publicKey,
+                    court: this.court.publicKey,
+                    authority: this.editAuthority.publicKey,
+                })
+                .signers([this.editAuthority])
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    getTreasury = () => {
+        return this.findProgramAddress("treasury", [this.court.publicKey]).publicKey;
+    }
+
+    setAmicusConfig = async (bond: anchor.BN, spamThresholdBps: number) => {
+        try {
+            await this.program.methods
+                .setAmicusConfig(
+                    this.courtName,
+                    { bond, spamThresholdBps }
+                )
+                .accounts({
+                    court: this.court.publicKey,
+                    authority: this.editAuthority.publicKey,
+                })
+                .signers([this.editAuthority])
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    // flags: [flag, juror] pairs closed back to the jurors, the bond settles once none are left open
+    settleAmicusBond = async (submitter: PublicKey, flags: { flag: PublicKey, juror: PublicKey }[] = []) => {
+        const submission = this.findProgramAddress("amicus", [this.dispute.publicKey, submitter]).publicKey;
+        const remainingAccounts: AccountMeta[] = flags.flatMap(({ flag, juror }) => [
+            { pubkey: flag, isSigner: false, isWritable: true },
+            { pubkey: juror, isSigner: false, isWritable: true },
+        ]);
+
+        try {
+            await this.program.methods
+                .settleAmicusBond(
+                    this.courtName,
+                    this.disputeID
+                )
+                .accounts({
+                    submission: submission,
+                    dispute: this.dispute.publicKey,
+                    court: this.court.publicKey,
+                    treasury: this.getTreasury(),
+                    submitter: submitter
+                })
+                .remainingAccounts(remainingAccounts)
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
 }
@@ -0,0 +1,126 @@
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { createMint, getOrCreateAssociatedTokenAccount, mintTo } from '@solana/spl-token';
+import { expect } from 'chai';
+import { maxDisputeVotes, decimals, courtName, multiDisputeOptions as disputeOptions } from './config';
+import { CourtSuite } from './court-suite';
+import { UserSuite } from './user-suite';
+
+const METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+describe('agora-court-juror-gating', () => {
+    //find the provider and set the anchor provider
+    let cs = new CourtSuite();
+    let partyOne = new UserSuite();
+    let partyTwo = new UserSuite();
+    let passHolder = new UserSuite();
+    let outsider = new UserSuite();
+    let collection = Keypair.generate().publicKey;
+
+    const expectFailure = async (call: () => Promise<any>, code: string) => {
+        let failed = false;
+        try {
+            await call();
+        } catch (err) {
+            failed = true;
+            expect(err.error.errorCode.code).to.equal(code);
+        }
+        expect(failed).to.be.true;
+    }
+
+    it('setup_court_and_dispute!', async () => {
+        await cs.setAccounts(decimals, courtName);
+        await cs.initCourt(maxDisputeVotes);
+        await cs.initDispute(disputeOptions);
+
+        let courtConfig = cs.getCourtConfig();
+        for (const user of [partyOne, partyTwo, passHolder, outsider]) {
+            await user.setAccounts(courtConfig);
+            await user.initRecord();
+        }
+
+        for (const party of [partyOne, partyTwo]) {
+            let ata = await party.getOrCreateRepATA(party.user.publicKey, false);
+            await cs.mintRepTokens(ata.address, disputeOptions.partyRepCost.toNumber());
+            await party.interact(cs.disputeID);
+        }
+        await partyOne.initCase(cs.disputeID, "I'm right guys trust");
+        await partyTwo.initCase(cs.disputeID, "Don't trust that bozo");
+
+        for (const juror of [passHolder, outsider]) {
+            let ata = await juror.getOrCreateRepATA(juror.user.publicKey, false);
+            await cs.mintRepTokens(ata.address, disputeOptions.voterRepRequired.toNumber());
+            await juror.depositStake(disputeOptions.voterRepRequired);
+        }
+    });
+
+    describe('allow_list!', () => {
+        it('only_edit_authority_adds_jurors!', async () => {
+            await expectFailure(() => cs.addJuror(passHolder.user.publicKey, passHolder.user), "InvalidEditAuthority");
+        });
+
+        it('add_juror!', async () => {
+            await cs.setJurorAllowlist(true);
+            await cs.addJuror(passHolder.user.publicKey);
+
+            let passState = await cs.program.account.jurorPass.fetch(passHolder.getJurorPass());
+            expect(passState.juror.toBase58()).to.equal(passHolder.user.publicKey.toBase58());
+        });
+
+        it('juror_without_pass_fails!', async () => {
+            await expectFailure(() => outsider.selectVote(cs.disputeID, partyOne.user.publicKey), "JurorNotAllowed");
+        });
+
+        it('juror_with_pass_votes!', async () => {
+            await passHolder.selectVote(cs.disputeID, partyOne.user.publicKey, { jurorPass: passHolder.getJurorPass() });
+
+            let recordState = await cs.program.account.voterRecord.fetch(passHolder.record.publicKey);
+            expect(recordState.claimQueue.length).to.equal(1);
+        });
+
+        it('remove_juror!', async () => {
+            await cs.removeJuror(passHolder.user.publicKey);
+
+            let passState = await cs.program.account.jurorPass.fetchNullable(passHolder.getJurorPass());
+            expect(passState).to.be.null;
+
+            await cs.setJurorAllowlist(false);
+        });
+    });
+
+    describe('collection_gate!', () => {
+        let nftMint: PublicKey;
+        let nftAta: PublicKey;
+
+        it('set_juror_collection!', async () => {
+            await cs.setJurorCollection({ collection: collection, replacesRep: false });
+
+            let courtState = await cs.program.account.court.fetch(cs.court.publicKey);
+            expect(courtState.jurorCollection.collection.toBase58()).to.equal(collection.toBase58());
+        });
+
+        it('juror_without_nft_fails!', async () => {
+            await expectFailure(() => outsider.selectVote(cs.disputeID, partyTwo.user.publicKey), "NotInJurorCollection");
+        });
+
+        it('nft_without_metaplex_metadata_fails!', async () => {
+            nftMint = await createMint(cs.connection, cs.editAuthority, cs.mintAuthority.publicKey, null, 0);
+            nftAta = (await getOrCreateAssociatedTokenAccount(cs.connection, cs.editAuthority, nftMint, outsider.user.publicKey)).address;
+            await mintTo(cs.connection, cs.editAuthority, nftMint, nftAta, cs.mintAuthority, 1);
+
+            // right address, but never created by the metadata program
+            let [nftMetadata] = PublicKey.findProgramAddressSync(
+                [Buffer.from("metadata"), METADATA_PROGRAM_ID.toBuffer(), nftMint.toBuffer()],
+                METADATA_PROGRAM_ID
+            );
+            await expectFailure(() => outsider.selectVote(cs.disputeID, partyTwo.user.publicKey, { nftAta, nftMetadata }), "CollectionMetadataMismatch");
+        });
+
+        it('cleared_collection_lets_juror_vote!', async () => {
+            await cs.setJurorCollection(null);
+            await outsider.selectVote(cs.disputeID, partyTwo.user.publicKey);
+
+            let recordState = await cs.program.account.voterRecord.fetch(outsider.record.publicKey);
+            expect(recordState.claimQueue.length).to.equal(1);
+        });
+    });
+});
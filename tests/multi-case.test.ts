@@ -64,6 +64,20 @@ describe('agora-court-basic', () => {
             payMint: null,
             numDisputes: new anchor.BN(0),
             maxDisputeVotes: maxDisputeVotes,
+            liveRecords: new anchor.BN(0),
+            unbondingPeriod: new anchor.BN(0),
+            crankTip: new anchor.BN(0),
+            soulbound: null,
+            bounds: null,
+            pendingAuthority: null,
+            pendingProtocol: null,
+            changeDelay: new anchor.BN(0),
+            status: { active: {} },
+            parent: null,
+            jurorAllowlist: false,
+            jurorCollection: null,
+            policyVersion: 0,
+            amicus: null,
             bump: cs.court.bump,
         }
 
@@ -87,6 +101,13 @@ describe('agora-court-basic', () => {
                     claimQueue: [],
                     currentlyStakedRep: new anchor.BN(0),
                     currentlyStakedPay: new anchor.BN(0),
+                    jurorStake: {
+                        free: new anchor.BN(0),
+                        locked: new anchor.BN(0),
+                        unbonding: new anchor.BN(0),
+                        unbondingEndsAt: new anchor.BN(0),
+                    },
+                    repDebt: new anchor.BN(0),
                     bump: element.bump,
                 }
 
@@ -108,10 +129,20 @@ describe('agora-court-basic', () => {
                 let expectedDisputeState = {
                     users: disputeOptions.users,
                     votes: new Array<anchor.BN>(disputeOptions.users.length).fill(new anchor.BN(0)),
+                    committedVotes: new anchor.BN(0),
                     status: { grace: {} },
                     interactions: 0,
                     submittedCases: 0,
                     config: disputeConfig,
+                    partyCosts: new Array(disputeOptions.users.length).fill({ rep: disputeConfig.repCost, pay: disputeConfig.payCost }),
+                    cases: new Array(disputeOptions.users.length).fill(false),
+                    appealBonds: new Array<anchor.BN>(disputeOptions.users.length).fill(new anchor.BN(0)),
+                    caseStakes: { rep: new anchor.BN(0), pay: new anchor.BN(0) },
+                    protocol: cs.protocol.publicKey,
+                    repMint: cs.repMint.publicKey,
+                    payMint: null,
+                    soulbound: null,
+                    policyVersion: 0,
                     bump: cs.dispute.bump
                 };
 
@@ -124,7 +155,7 @@ describe('agora-court-basic', () => {
                 expect(balance).to.eq(0);
 
                 // check reputation vault
-                let repVault = cs.getRepATA(cs.dispute.publicKey);
+                let repVault = cs.getRepVault(cs.dispute.publicKey);
                 balance = await cs.getTokenBalance(repVault);
                 expect(balance).to.eq(disputeConfig.protocolRep.toNumber());
 
@@ -146,7 +177,7 @@ describe('agora-court-basic', () => {
                 expect(disputeState.interactions).to.equal(1);
 
                 // check token transfer
-                repVault = cs.getRepATA(cs.dispute.publicKey);
+                repVault = cs.getRepVault(cs.dispute.publicKey);
                 balance = await cs.getTokenBalance(repVault);
 
                 expect(balance).to.eq(first_balance);
@@ -163,7 +194,7 @@ describe('agora-court-basic', () => {
                 expect(disputeState.interactions).to.equal(2);
 
                 // check token transfer
-                repVault = cs.getRepATA(cs.dispute.publicKey);
+                repVault = cs.getRepVault(cs.dispute.publicKey);
                 balance = await cs.getTokenBalance(repVault);
 
                 expect(balance).to.eq(second_balance);
@@ -184,11 +215,11 @@ describe('agora-court-basic', () => {
 
                 // check case account
                 let caseState = await cs.program.account.case.fetch(userOne.case.publicKey);
-                let expectedCase = {
-                    evidence: evidence,
-                    bump: userOne.case.bump
-                };
-                expect(JSON.stringify(caseState)).to.equal(JSON.stringify(expectedCase));
+                expect(caseState.evidence.length).to.equal(1);
+                expect(caseState.evidence[0].uri).to.equal(evidence);
+                expect(caseState.evidence[0].mimeType).to.equal("text/plain");
+                expect(caseState.evidence[0].submitter.toBase58()).to.equal(userOne.user.publicKey.toBase58());
+                expect(caseState.bump).to.equal(userOne.case.bump);
 
                 // SECOND
                 evidence = "Don't trust that bozo";
@@ -202,11 +233,11 @@ describe('agora-court-basic', () => {
 
                 // check case account
                 caseState = await cs.program.account.case.fetch(userTwo.case.publicKey);
-                expectedCase = {
-                    evidence: evidence,
-                    bump: userTwo.case.bump
-                };
-                expect(JSON.stringify(caseState)).to.equal(JSON.stringify(expectedCase));
+                expect(caseState.evidence.length).to.equal(1);
+                expect(caseState.evidence[0].uri).to.equal(evidence);
+                expect(caseState.evidence[0].mimeType).to.equal("text/plain");
+                expect(caseState.evidence[0].submitter.toBase58()).to.equal(userTwo.user.publicKey.toBase58());
+                expect(caseState.bump).to.equal(userTwo.case.bump);
 
                 console.log("       ✔ " + str + "!");
             }
@@ -64,6 +64,20 @@ describe('agora-court-no-reveal-inconclusive', () => {
             payMint: null,
             numDisputes: new anchor.BN(0),
             maxDisputeVotes: maxDisputeVotes,
+            liveRecords: new anchor.BN(0),
+            unbondingPeriod: new anchor.BN(0),
+            crankTip: new anchor.BN(0),
+            soulbound: null,
+            bounds: null,
+            pendingAuthority: null,
+            pendingProtocol: null,
+            changeDelay: new anchor.BN(0),
+            status: { active: {} },
+            parent: null,
+            jurorAllowlist: false,
+            jurorCollection: null,
+            policyVersion: 0,
+            amicus: null,
             bump: cs.court.bump,
         }
 
@@ -80,10 +94,20 @@ describe('agora-court-no-reveal-inconclusive', () => {
         let expectedDisputeState = {
             users: disputeOptions.users,
             votes: new Array<anchor.BN>(disputeOptions.users.length).fill(new anchor.BN(0)),
+            committedVotes: new anchor.BN(0),
             status: { grace: {} },
             interactions: 0,
             submittedCases: 0,
             config: disputeConfig,
+            partyCosts: new Array(disputeOptions.users.length).fill({ rep: disputeConfig.repCost, pay: disputeConfig.payCost }),
+            cases: new Array(disputeOptions.users.length).fill(false),
+            appealBonds: new Array<anchor.BN>(disputeOptions.users.length).fill(new anchor.BN(0)),
+            caseStakes: { rep: new anchor.BN(0), pay: new anchor.BN(0) },
+            protocol: cs.protocol.publicKey,
+            repMint: cs.repMint.publicKey,
+            payMint: null,
+            soulbound: null,
+            policyVersion: 0,
             bump: cs.dispute.bump
         };
 
@@ -96,7 +120,7 @@ describe('agora-court-no-reveal-inconclusive', () => {
         expect(balance).to.eq(0);
 
         // check reputation vault
-        let repVault = cs.getRepATA(cs.dispute.publicKey);
+        let repVault = cs.getRepVault(cs.dispute.publicKey);
         balance = await cs.getTokenBalance(repVault);
 
         expect(balance).to.eq(disputeConfig.protocolRep.toNumber());
@@ -118,6 +142,13 @@ describe('agora-court-no-reveal-inconclusive', () => {
                 claimQueue: [],
                 currentlyStakedRep: new anchor.BN(0),
                 currentlyStakedPay: new anchor.BN(0),
+                jurorStake: {
+                    free: new anchor.BN(0),
+                    locked: new anchor.BN(0),
+                    unbonding: new anchor.BN(0),
+                    unbondingEndsAt: new anchor.BN(0),
+                },
+                repDebt: new anchor.BN(0),
                 bump: element.bump,
             }
 
@@ -142,7 +173,7 @@ describe('agora-court-no-reveal-inconclusive', () => {
             expect(disputeState.interactions).to.equal(1);
 
             // check token transfer
-            let repVault = cs.getRepATA(cs.dispute.publicKey);
+            let repVault = cs.getRepVault(cs.dispute.publicKey);
             let balance = await cs.getTokenBalance(repVault);
 
             expect(balance).to.eq(first_balance);
@@ -165,7 +196,7 @@ describe('agora-court-no-reveal-inconclusive', () => {
             expect(disputeState.interactions).to.equal(2);
 
             // check token transfer
-            let repVault = cs.getRepATA(cs.dispute.publicKey);
+            let repVault = cs.getRepVault(cs.dispute.publicKey);
             let balance = await cs.getTokenBalance(repVault);
 
             expect(balance).to.eq(second_balance);
@@ -189,11 +220,11 @@ describe('agora-court-no-reveal-inconclusive', () => {
 
             // check case account
             let caseState = await cs.program.account.case.fetch(userOne.case.publicKey);
-            let expectedCase = {
-                evidence: evidence,
-                bump: userOne.case.bump
-            };
-            expect(JSON.stringify(caseState)).to.equal(JSON.stringify(expectedCase));
+            expect(caseState.evidence.length).to.equal(1);
+            expect(caseState.evidence[0].uri).to.equal(evidence);
+            expect(caseState.evidence[0].mimeType).to.equal("text/plain");
+            expect(caseState.evidence[0].submitter.toBase58()).to.equal(userOne.user.publicKey.toBase58());
+            expect(caseState.bump).to.equal(userOne.case.bump);
 
             // check voter record
             let recordState = await cs.program.account.voterRecord.fetch(userOne.record.publicKey);
@@ -222,11 +253,11 @@ describe('agora-court-no-reveal-inconclusive', () => {
 
             // check case account
             let caseState = await cs.program.account.case.fetch(userTwo.case.publicKey);
-            let expectedCase = {
-                evidence: evidence,
-                bump: userTwo.case.bump
-            };
-            expect(JSON.stringify(caseState)).to.equal(JSON.stringify(expectedCase));
+            expect(caseState.evidence.length).to.equal(1);
+            expect(caseState.evidence[0].uri).to.equal(evidence);
+            expect(caseState.evidence[0].mimeType).to.equal("text/plain");
+            expect(caseState.evidence[0].submitter.toBase58()).to.equal(userTwo.user.publicKey.toBase58());
+            expect(caseState.bump).to.equal(userTwo.case.bump);
 
             // check voter queue
             let recordState = await cs.program.account.voterRecord.fetch(userTwo.record.publicKey);
@@ -249,6 +280,7 @@ describe('agora-court-no-reveal-inconclusive', () => {
             // mint tokens
             let repATA = await userThree.getOrCreateRepATA(userThree.user.publicKey, false);
             await cs.mintRepTokens(repATA.address, disputeOptions.voterRepRequired.toNumber());
+            await userThree.depositStake(disputeOptions.voterRepRequired);
 
             // call ix
             let hashArr = await userThree.selectVote(cs.disputeID, userTwo.user.publicKey);
@@ -267,15 +299,16 @@ describe('agora-court-no-reveal-inconclusive', () => {
             }
             expect(JSON.stringify(recordState.claimQueue[0])).to.equal(JSON.stringify(expectedRecordQueue));
 
-            // check token transfer
-            let repVault = cs.getRepATA(cs.dispute.publicKey);
-            let expectedBalance = disputeOptions.protocolRep.toNumber() + 2*disputeOptions.partyRepCost.toNumber() + disputeOptions.voterRepCost.toNumber();
+            // check voter stake stays in the court stake vault
+            let repVault = cs.getRepVault(cs.dispute.publicKey);
+            let expectedBalance = disputeOptions.protocolRep.toNumber() + 2*disputeOptions.partyRepCost.toNumber();
             let balance = await cs.getTokenBalance(repVault);
 
             expect(balance).to.eq(expectedBalance);
 
-            // check record currently staked rep
-            expect(recordState.currentlyStakedRep.eq(disputeConfig.voterRepCost)).to.be.true;
+            // check record juror stake
+            expect(recordState.jurorStake.locked.eq(disputeConfig.voterRepCost)).to.be.true;
+            expect(recordState.jurorStake.free.eq(disputeConfig.voterRepRequired.sub(disputeConfig.voterRepCost))).to.be.true;
         });
     });
 
@@ -350,10 +383,9 @@ describe('agora-court-no-reveal-inconclusive', () => {
                 expect(recordState.currentlyStakedRep.eqn(0)).to.be.true;
 
                 // check repATA
-                let repAta = cs.getRepATA(userThree.user.publicKey);
-                let balance = await cs.getTokenBalance(repAta);
-                let expectedBalance = disputeOptions.voterRepRequired;
-                expect(balance).to.equal(expectedBalance.toNumber());
+                // check juror stake refunded
+                expect(recordState.jurorStake.locked.eqn(0)).to.be.true;
+                expect(recordState.jurorStake.free.eq(disputeOptions.voterRepRequired)).to.be.true;
             });
         });
     });
@@ -62,6 +62,20 @@ describe('agora-court-no-vote-inconclusive', () => {
             payMint: null,
             numDisputes: new anchor.BN(0),
             maxDisputeVotes: maxDisputeVotes,
+            liveRecords: new anchor.BN(0),
+            unbondingPeriod: new anchor.BN(0),
+            crankTip: new anchor.BN(0),
+            soulbound: null,
+            bounds: null,
+            pendingAuthority: null,
+            pendingProtocol: null,
+            changeDelay: new anchor.BN(0),
+            status: { active: {} },
+            parent: null,
+            jurorAllowlist: false,
+            jurorCollection: null,
+            policyVersion: 0,
+            amicus: null,
             bump: cs.court.bump,
         }
 
@@ -78,10 +92,20 @@ describe('agora-court-no-vote-inconclusive', () => {
         let expectedDisputeState = {
             users: disputeOptions.users,
             votes: new Array<anchor.BN>(disputeOptions.users.length).fill(new anchor.BN(0)),
+            committedVotes: new anchor.BN(0),
             status: { grace: {} },
             interactions: 0,
             submittedCases: 0,
             config: disputeConfig,
+            partyCosts: new Array(disputeOptions.users.length).fill({ rep: disputeConfig.repCost, pay: disputeConfig.payCost }),
+            cases: new Array(disputeOptions.users.length).fill(false),
+            appealBonds: new Array<anchor.BN>(disputeOptions.users.length).fill(new anchor.BN(0)),
+            caseStakes: { rep: new anchor.BN(0), pay: new anchor.BN(0) },
+            protocol: cs.protocol.publicKey,
+            repMint: cs.repMint.publicKey,
+            payMint: null,
+            soulbound: null,
+            policyVersion: 0,
             bump: cs.dispute.bump
         };
 
@@ -94,7 +118,7 @@ describe('agora-court-no-vote-inconclusive', () => {
         expect(balance).to.eq(0);
 
         // check reputation vault
-        let repVault = cs.getRepATA(cs.dispute.publicKey);
+        let repVault = cs.getRepVault(cs.dispute.publicKey);
         balance = await cs.getTokenBalance(repVault);
 
         expect(balance).to.eq(disputeConfig.protocolRep.toNumber());
@@ -115,6 +139,13 @@ describe('agora-court-no-vote-inconclusive', () => {
                 claimQueue: [],
                 currentlyStakedRep: new anchor.BN(0),
                 currentlyStakedPay: new anchor.BN(0),
+                jurorStake: {
+                    free: new anchor.BN(0),
+                    locked: new anchor.BN(0),
+                    unbonding: new anchor.BN(0),
+                    unbondingEndsAt: new anchor.BN(0),
+                },
+                repDebt: new anchor.BN(0),
                 bump: element.bump,
             }
 
@@ -139,7 +170,7 @@ describe('agora-court-no-vote-inconclusive', () => {
             expect(disputeState.interactions).to.equal(1);
 
             // check token transfer
-            let repVault = cs.getRepATA(cs.dispute.publicKey);
+            let repVault = cs.getRepVault(cs.dispute.publicKey);
             let balance = await cs.getTokenBalance(repVault);
 
             expect(balance).to.eq(first_balance);
@@ -162,7 +193,7 @@ describe('agora-court-no-vote-inconclusive', () => {
             expect(disputeState.interactions).to.equal(2);
 
             // check token transfer
-            let repVault = cs.getRepATA(cs.dispute.publicKey);
+            let repVault = cs.getRepVault(cs.dispute.publicKey);
             let balance = await cs.getTokenBalance(repVault);
 
             expect(balance).to.eq(second_balance);
@@ -186,11 +217,11 @@ describe('agora-court-no-vote-inconclusive', () => {
 
             // check case account
             let caseState = await cs.program.account.case.fetch(userOne.case.publicKey);
-            let expectedCase = {
-                evidence: evidence,
-                bump: userOne.case.bump
-            };
-            expect(JSON.stringify(caseState)).to.equal(JSON.stringify(expectedCase));
+            expect(caseState.evidence.length).to.equal(1);
+            expect(caseState.evidence[0].uri).to.equal(evidence);
+            expect(caseState.evidence[0].mimeType).to.equal("text/plain");
+            expect(caseState.evidence[0].submitter.toBase58()).to.equal(userOne.user.publicKey.toBase58());
+            expect(caseState.bump).to.equal(userOne.case.bump);
 
             // check voter record
             let recordState = await cs.program.account.voterRecord.fetch(userOne.record.publicKey);
@@ -219,11 +250,11 @@ describe('agora-court-no-vote-inconclusive', () => {
 
             // check case account
             let caseState = await cs.program.account.case.fetch(userTwo.case.publicKey);
-            let expectedCase = {
-                evidence: evidence,
-                bump: userTwo.case.bump
-            };
-            expect(JSON.stringify(caseState)).to.equal(JSON.stringify(expectedCase));
+            expect(caseState.evidence.length).to.equal(1);
+            expect(caseState.evidence[0].uri).to.equal(evidence);
+            expect(caseState.evidence[0].mimeType).to.equal("text/plain");
+            expect(caseState.evidence[0].submitter.toBase58()).to.equal(userTwo.user.publicKey.toBase58());
+            expect(caseState.bump).to.equal(userTwo.case.bump);
 
             // check voter queue
             let recordState = await cs.program.account.voterRecord.fetch(userTwo.record.publicKey);
@@ -0,0 +1,105 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { maxDisputeVotes, decimals, courtName, noRevealDisputeOptions as disputeOptions } from './config';
+import { CourtSuite, DisputeConfig } from './court-suite';
+import { UserSuite } from './user-suite';
+
+// waits until the given unix timestamp (plus a buffer) has passed on the validator
+const waitUntil = async (timestamp: anchor.BN, buffer: number = 3) => {
+    let curTime = Math.floor(Date.now() / 1000);
+    if (curTime < timestamp.toNumber()) {
+        await new Promise<void>((resolve) => setTimeout(resolve, (timestamp.toNumber() - curTime + buffer) * 1000));
+    }
+}
+
+describe('agora-court-non-revealer', () => {
+    //find the provider and set the anchor provider
+    let cs = new CourtSuite();
+    let partyOne = new UserSuite();
+    let partyTwo = new UserSuite();
+    let revealer = new UserSuite();
+    let nonRevealer = new UserSuite();
+    let disputeConfig: DisputeConfig;
+
+    it('setup_court_and_dispute!', async () => {
+        await cs.setAccounts(decimals, courtName);
+        await cs.initCourt(maxDisputeVotes);
+        disputeConfig = await cs.initDispute(disputeOptions);
+
+        let courtConfig = cs.getCourtConfig();
+        for (const user of [partyOne, partyTwo, revealer, nonRevealer]) {
+            await user.setAccounts(courtConfig);
+            await user.initRecord();
+        }
+    });
+
+    it('parties_submit_cases!', async () => {
+        for (const party of [partyOne, partyTwo]) {
+            let ata = await party.getOrCreateRepATA(party.user.publicKey, false);
+            await cs.mintRepTokens(ata.address, disputeOptions.partyRepCost.toNumber());
+            await party.interact(cs.disputeID);
+        }
+
+        await partyOne.initCase(cs.disputeID, "I'm right guys trust");
+        await partyTwo.initCase(cs.disputeID, "Don't trust that bozo");
+    });
+
+    it('both_voters_commit!', async () => {
+        for (const voter of [revealer, nonRevealer]) {
+            let ata = await voter.getOrCreateRepATA(voter.user.publicKey, false);
+            await cs.mintRepTokens(ata.address, disputeOptions.voterRepRequired.toNumber());
+            await voter.depositStake(disputeOptions.voterRepRequired);
+            await voter.selectVote(cs.disputeID, partyTwo.user.publicKey);
+        }
+
+        let disputeState = await cs.program.account.dispute.fetch(cs.dispute.publicKey);
+        expect(disputeState.committedVotes.eqn(2)).to.be.true;
+    });
+
+    it('only_one_voter_reveals!', async () => {
+        await waitUntil(disputeConfig.votingEndsAt, 5);
+        await revealer.revealVote(cs.disputeID);
+
+        await waitUntil(disputeConfig.disputeEndsAt);
+        await cs.closeDispute();
+
+        let disputeState = await cs.program.account.dispute.fetch(cs.dispute.publicKey);
+        expect(JSON.stringify(disputeState.status)).to.equal(JSON.stringify({ concluded: { winner: partyTwo.user.publicKey } }));
+        expect(disputeState.votes[1].eqn(1)).to.be.true;
+    });
+
+    it('non_revealer_forfeits_stake!', async () => {
+        await nonRevealer.claim(cs.disputeID);
+
+        let recordState = await cs.program.account.voterRecord.fetch(nonRevealer.record.publicKey);
+        expect(recordState.claimQueue).to.be.empty;
+        expect(recordState.jurorStake.locked.eqn(0)).to.be.true;
+        expect(recordState.jurorStake.free.eq(disputeOptions.voterRepRequired.sub(disputeOptions.voterRepCost))).to.be.true;
+    });
+
+    it('revealer_is_credited_both_stakes!', async () => {
+        await revealer.claim(cs.disputeID);
+
+        let recordState = await cs.program.account.voterRecord.fetch(revealer.record.publicKey);
+        expect(recordState.claimQueue).to.be.empty;
+        expect(recordState.jurorStake.locked.eqn(0)).to.be.true;
+        expect(recordState.jurorStake.free.eq(disputeOptions.voterRepRequired.add(disputeOptions.voterRepCost))).to.be.true;
+
+        // the losing party's stake and the protocol subsidy
+        let balance = await cs.getTokenBalance(cs.getRepATA(revealer.user.publicKey));
+        expect(balance).to.equal(disputeOptions.partyRepCost.add(disputeOptions.protocolRep).toNumber());
+    });
+
+    it('stake_vault_is_fully_accounted_for!', async () => {
+        // both deposits are still in the vault, all of it owed to voters' free stake
+        let balance = await cs.getTokenBalance(revealer.getStakeVault());
+        expect(balance).to.equal(disputeOptions.voterRepRequired.muln(2).toNumber());
+
+        let free = new anchor.BN(0);
+        for (const voter of [revealer, nonRevealer]) {
+            let recordState = await cs.program.account.voterRecord.fetch(voter.record.publicKey);
+            free = free.add(recordState.jurorStake.free);
+        }
+        expect(free.toNumber()).to.equal(balance);
+    });
+});
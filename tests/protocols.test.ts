@@ -0,0 +1,102 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Keypair } from '@solana/web3.js';
+import { expect } from 'chai';
+import { maxDisputeVotes, decimals, courtName, basicDisputeOptions as disputeOptions } from './config';
+import { CourtSuite } from './court-suite';
+
+describe('agora-court-protocols', () => {
+    //find the provider and set the anchor provider
+    let cs = new CourtSuite();
+    let registered = Keypair.generate();
+    let stranger = Keypair.generate();
+
+    it('setup_court!', async () => {
+        await cs.setAccounts(decimals, courtName);
+        await cs.initCourt(maxDisputeVotes);
+    });
+
+    it('only_edit_authority_adds_protocols!', async () => {
+        await cs.requestAirdrop(stranger.publicKey, 1);
+
+        let failed = false;
+        try {
+            await cs.addProtocol(registered.publicKey, null, stranger);
+        } catch (err) {
+            failed = true;
+            expect(err.error.errorCode.code).to.equal("InvalidEditAuthority");
+        }
+        expect(failed).to.be.true;
+    });
+
+    it('zero_window_rate_limit_fails!', async () => {
+        let failed = false;
+        try {
+            await cs.addProtocol(registered.publicKey, { maxDisputes: 1, window: new anchor.BN(0) });
+        } catch (err) {
+            failed = true;
+            expect(err.error.errorCode.code).to.equal("InvalidRateLimit");
+        }
+        expect(failed).to.be.true;
+    });
+
+    it('add_protocol!', async () => {
+        await cs.addProtocol(registered.publicKey, { maxDisputes: 1, window: new anchor.BN(60 * 60) });
+
+        let entryState = await cs.program.account.protocolEntry.fetch(cs.getProtocolEntry(registered.publicKey));
+        expect(entryState.protocol.toBase58()).to.equal(registered.publicKey.toBase58());
+        expect(entryState.rateLimit.maxDisputes).to.equal(1);
+        expect(entryState.windowDisputes).to.equal(0);
+    });
+
+    it('unregistered_protocol_fails!', async () => {
+        let failed = false;
+        try {
+            await cs.initDispute(disputeOptions, stranger);
+        } catch (err) {
+            failed = true;
+            expect(err.error.errorCode.code).to.equal("InvalidProtocol");
+        }
+        expect(failed).to.be.true;
+    });
+
+    it('registered_protocol_opens_dispute!', async () => {
+        await cs.initDispute(disputeOptions, registered, cs.getProtocolEntry(registered.publicKey));
+
+        let disputeState = await cs.program.account.dispute.fetch(cs.dispute.publicKey);
+        expect(disputeState.protocol.toBase58()).to.equal(registered.publicKey.toBase58());
+
+        let entryState = await cs.program.account.protocolEntry.fetch(cs.getProtocolEntry(registered.publicKey));
+        expect(entryState.windowDisputes).to.equal(1);
+    });
+
+    it('rate_limited_protocol_fails!', async () => {
+        // one dispute per hour, already used above
+        let failed = false;
+        try {
+            await cs.initDispute(disputeOptions, registered, cs.getProtocolEntry(registered.publicKey));
+        } catch (err) {
+            failed = true;
+            expect(err.error.errorCode.code).to.equal("ProtocolRateLimited");
+        }
+        expect(failed).to.be.true;
+
+        // the court's own protocol is never rate limited
+        await cs.initDispute(disputeOptions);
+    });
+
+    it('removed_protocol_fails!', async () => {
+        await cs.removeProtocol(registered.publicKey);
+
+        let entryState = await cs.program.account.protocolEntry.fetchNullable(cs.getProtocolEntry(registered.publicKey));
+        expect(entryState).to.be.null;
+
+        let failed = false;
+        try {
+            await cs.initDispute(disputeOptions, registered);
+        } catch (err) {
+            failed = true;
+            expect(err.error.errorCode.code).to.equal("InvalidProtocol");
+        }
+        expect(failed).to.be.true;
+    });
+});
@@ -0,0 +1,147 @@
+import * as anchor from '@coral-xyz/anchor';
+import { PublicKey } from "@solana/web3.js";
+import { expect } from 'chai';
+import { transfer } from "@solana/spl-token";
+import {
+    maxDisputeVotes,
+    decimals,
+    courtName,
+    soulboundDisputeOptions as disputeOptions,
+    soulboundCoherentReward as coherentReward,
+    soulboundIncoherentSlash as incoherentSlash,
+} from './config';
+import { CourtSuite, DisputeConfig } from './court-suite';
+import { UserSuite } from './user-suite';
+
+// waits until the given unix timestamp (plus a buffer) has passed on the validator
+const waitUntil = async (timestamp: anchor.BN, buffer: number = 3) => {
+    let curTime = Math.floor(Date.now() / 1000);
+    if (curTime < timestamp.toNumber()) {
+        await new Promise<void>((resolve) => setTimeout(resolve, (timestamp.toNumber() - curTime + buffer) * 1000));
+    }
+}
+
+describe('agora-court-soulbound', () => {
+    //find the provider and set the anchor provider
+    let cs = new CourtSuite();
+    let partyOne = new UserSuite();
+    let partyTwo = new UserSuite();
+    let voterOne = new UserSuite();
+    let voterTwo = new UserSuite();
+    let voterThree = new UserSuite();
+    let disputeConfig: DisputeConfig;
+
+    // runs a dispute between partyOne and partyTwo, voters pick a side each
+    const runDispute = async (votes: [UserSuite, UserSuite][]) => {
+        disputeConfig = await cs.initDispute(disputeOptions);
+
+        await partyOne.interact(cs.disputeID);
+        await partyTwo.interact(cs.disputeID);
+        await partyOne.initCase(cs.disputeID, "I'm right guys trust");
+        await partyTwo.initCase(cs.disputeID, "Don't trust that bozo");
+
+        for (const [voter, candidate] of votes) {
+            await voter.selectVote(cs.disputeID, candidate.user.publicKey);
+        }
+
+        await waitUntil(disputeConfig.votingEndsAt, 5);
+        for (const [voter, _] of votes) {
+            await voter.revealVote(cs.disputeID);
+        }
+
+        await waitUntil(disputeConfig.disputeEndsAt);
+        await cs.closeDispute();
+
+        let disputeState = await cs.program.account.dispute.fetch(cs.dispute.publicKey);
+        expect(JSON.stringify(disputeState.status)).to.equal(JSON.stringify({ concluded: { winner: partyTwo.user.publicKey } }));
+    }
+
+    it('initialize_soulbound_court!', async () => {
+        await cs.setAccounts(decimals, courtName);
+        await cs.initSoulboundCourt(maxDisputeVotes, coherentReward, incoherentSlash);
+
+        let courtState = await cs.program.account.court.fetch(cs.court.publicKey);
+        expect(courtState.repMint.equals(cs.repMintKey)).to.be.true;
+        expect(courtState.soulbound.coherentReward.eq(coherentReward)).to.be.true;
+        expect(courtState.soulbound.incoherentSlash.eq(incoherentSlash)).to.be.true;
+
+        // court owns the mint, so nobody else can mint rep
+        let mint = await cs.connection.getParsedAccountInfo(cs.repMintKey);
+        let info = (mint.value.data as any).parsed.info;
+        expect(info.mintAuthority).to.equal(cs.court.publicKey.toBase58());
+        expect(info.freezeAuthority).to.equal(cs.court.publicKey.toBase58());
+        expect(info.decimals).to.equal(decimals);
+    });
+
+    it('async_user_constructors!', async () => {
+        let courtConfig = cs.getCourtConfig();
+
+        for (const user of [partyOne, partyTwo, voterOne, voterTwo, voterThree]) {
+            await user.setAccounts(courtConfig);
+            await user.initRecord();
+        }
+    });
+
+    it('staked_rep_dispute_fails!', async () => {
+        // soulbound rep can't be moved into a vault
+        let failed = false;
+        try {
+            await cs.initDispute({ ...disputeOptions, protocolRep: new anchor.BN(1) });
+        } catch (err) {
+            failed = true;
+            expect(err.error.errorCode.code).to.equal("SoulboundReputationNotTransferable");
+        }
+        expect(failed).to.be.true;
+    });
+
+    it('coherent_voters_are_minted_rep!', async () => {
+        await runDispute([[voterOne, partyTwo], [voterTwo, partyTwo]]);
+
+        for (const user of [partyOne, partyTwo, voterOne, voterTwo]) {
+            await user.claim(cs.disputeID);
+        }
+
+        for (const voter of [voterOne, voterTwo]) {
+            let balance = await cs.getTokenBalance(cs.getRepATA(voter.user.publicKey));
+            expect(balance).to.equal(coherentReward.toNumber());
+        }
+    });
+
+    it('soulbound_rep_is_frozen!', async () => {
+        // minted rep is left frozen, so it can't be moved out of the voter's account
+        let failed = false;
+        try {
+            await transfer(
+                cs.connection,
+                voterOne.user,
+                cs.getRepATA(voterOne.user.publicKey),
+                cs.getRepATA(voterTwo.user.publicKey),
+                voterOne.user,
+                1
+            );
+        } catch (err) {
+            failed = true;
+        }
+        expect(failed).to.be.true;
+
+        let balance = await cs.getTokenBalance(cs.getRepATA(voterOne.user.publicKey));
+        expect(balance).to.equal(coherentReward.toNumber());
+    });
+
+    it('incoherent_voter_is_burned!', async () => {
+        await runDispute([[voterOne, partyTwo], [voterTwo, partyOne], [voterThree, partyTwo]]);
+
+        for (const user of [partyOne, partyTwo, voterOne, voterTwo, voterThree]) {
+            await user.claim(cs.disputeID);
+        }
+
+        let balance = await cs.getTokenBalance(cs.getRepATA(voterOne.user.publicKey));
+        expect(balance).to.equal(coherentReward.muln(2).toNumber());
+
+        balance = await cs.getTokenBalance(cs.getRepATA(voterTwo.user.publicKey));
+        expect(balance).to.equal(coherentReward.sub(incoherentSlash).toNumber());
+
+        balance = await cs.getTokenBalance(cs.getRepATA(voterThree.user.publicKey));
+        expect(balance).to.equal(coherentReward.toNumber());
+    });
+});
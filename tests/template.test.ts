@@ -0,0 +1,103 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Keypair } from '@solana/web3.js';
+import { expect } from 'chai';
+import { maxDisputeVotes, decimals, courtName } from './config';
+import { CourtSuite, DisputeDefaults, TemplatePolicy } from './court-suite';
+
+const OVERRIDE_VOTING_DURATION = 1 << 2;
+
+describe('agora-court-template', () => {
+    //find the provider and set the anchor provider
+    let cs = new CourtSuite();
+    let users = [Keypair.generate().publicKey, Keypair.generate().publicKey];
+    let templateName = "standard";
+
+    let defaults: DisputeDefaults = {
+        graceDuration: new anchor.BN(60),
+        initCasesDuration: new anchor.BN(60),
+        votingDuration: new anchor.BN(60),
+        revealDuration: new anchor.BN(60),
+        voterRepRequired: new anchor.BN(5),
+        voterRepCost: new anchor.BN(0),
+        repCost: new anchor.BN(15),
+        payCost: new anchor.BN(0),
+        minVotes: new anchor.BN(3),
+        protocolPay: new anchor.BN(0),
+        protocolRep: new anchor.BN(0)
+    };
+
+    // named parties paying the template costs, votes last between one and ten minutes
+    let policy: TemplatePolicy = {
+        bounds: {
+            minPhaseDuration: new anchor.BN(30),
+            maxPhaseDuration: new anchor.BN(600),
+            minVotes: new anchor.BN(1),
+            maxVoterRepRequired: new anchor.BN(100),
+            maxVoterRepCost: new anchor.BN(100),
+            minRepCost: new anchor.BN(0),
+            maxRepCost: new anchor.BN(100),
+            minPayCost: new anchor.BN(0),
+            maxPayCost: new anchor.BN(100),
+            maxParties: 2,
+        },
+        allowPartyCosts: false,
+        allowOpenSlots: false
+    };
+
+    const expectFailure = async (call: () => Promise<void>, code: string) => {
+        let failed = false;
+        try {
+            await call();
+        } catch (err) {
+            failed = true;
+            expect(err.error.errorCode.code).to.equal(code);
+        }
+        expect(failed).to.be.true;
+    }
+
+    it('setup_court!', async () => {
+        await cs.setAccounts(decimals, courtName);
+        await cs.initCourt(maxDisputeVotes);
+    });
+
+    it('invalid_policy_bounds_fail!', async () => {
+        let badPolicy = { ...policy, bounds: { ...policy.bounds, maxParties: 0 } };
+        await expectFailure(() => cs.setDisputeTemplate(templateName, defaults, OVERRIDE_VOTING_DURATION, badPolicy), "InvalidConfigBounds");
+    });
+
+    it('set_template!', async () => {
+        await cs.setDisputeTemplate(templateName, defaults, OVERRIDE_VOTING_DURATION, policy);
+
+        let templateState = await cs.program.account.disputeTemplate.fetch(cs.getTemplate(templateName));
+        expect(templateState.overridable).to.equal(OVERRIDE_VOTING_DURATION);
+        expect(templateState.policy.allowPartyCosts).to.be.false;
+        expect(templateState.policy.bounds.maxParties).to.equal(2);
+    });
+
+    it('dispute_from_template!', async () => {
+        await cs.initTemplateDispute(templateName, users, [], { votingDuration: new anchor.BN(120) });
+
+        let disputeState = await cs.program.account.dispute.fetch(cs.dispute.publicKey);
+        let config = disputeState.config;
+        expect(config.minVotes.eq(defaults.minVotes)).to.be.true;
+        expect(config.repCost.eq(defaults.repCost)).to.be.true;
+        expect(config.votingEndsAt.sub(config.initCasesEndsAt).toNumber()).to.equal(120);
+    });
+
+    it('locked_field_override_fails!', async () => {
+        await expectFailure(() => cs.initTemplateDispute(templateName, users, [], { minVotes: new anchor.BN(1) }), "TemplateFieldLocked");
+    });
+
+    it('override_out_of_policy_bounds_fails!', async () => {
+        await expectFailure(() => cs.initTemplateDispute(templateName, users, [], { votingDuration: new anchor.BN(3600) }), "PhaseTooLong");
+    });
+
+    it('party_costs_fail_when_locked!', async () => {
+        let partyCosts = users.map(() => ({ rep: new anchor.BN(20), pay: new anchor.BN(0) }));
+        await expectFailure(() => cs.initTemplateDispute(templateName, users, partyCosts), "TemplatePartyCostsLocked");
+    });
+
+    it('open_slots_fail_when_locked!', async () => {
+        await expectFailure(() => cs.initTemplateDispute(templateName, [users[0], null]), "TemplateOpenSlotsLocked");
+    });
+});
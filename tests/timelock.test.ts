@@ -0,0 +1,102 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { createMint } from '@solana/spl-token';
+import { expect } from 'chai';
+import { maxDisputeVotes, decimals, courtName } from './config';
+import { CourtSuite } from './court-suite';
+import { UserSuite } from './user-suite';
+
+// waits until the given unix timestamp (plus a buffer) has passed on the validator
+const waitUntil = async (timestamp: anchor.BN, buffer: number = 3) => {
+    let curTime = Math.floor(Date.now() / 1000);
+    if (curTime < timestamp.toNumber()) {
+        await new Promise<void>((resolve) => setTimeout(resolve, (timestamp.toNumber() - curTime + buffer) * 1000));
+    }
+}
+
+describe('agora-court-timelock', () => {
+    //find the provider and set the anchor provider
+    let cs = new CourtSuite();
+    let juror = new UserSuite();
+    let stranger = Keypair.generate();
+    let changeDelay = 10;
+    let stake = new anchor.BN(5);
+    let newRepMint: PublicKey;
+
+    const expectFailure = async (call: () => Promise<void>, code: string) => {
+        let failed = false;
+        try {
+            await call();
+        } catch (err) {
+            failed = true;
+            expect(err.error.errorCode.code).to.equal(code);
+        }
+        expect(failed).to.be.true;
+    }
+
+    it('setup_court!', async () => {
+        await cs.setAccounts(decimals, courtName);
+        await cs.initCourt(maxDisputeVotes);
+
+        await juror.setAccounts(cs.getCourtConfig());
+        await juror.initRecord();
+    });
+
+    it('first_change_applies_at_once!', async () => {
+        // the court starts without a delay, this change sets one
+        await cs.editCourt(maxDisputeVotes, 0, 0, changeDelay);
+        await cs.applyCourtChange();
+
+        let courtState = await cs.program.account.court.fetch(cs.court.publicKey);
+        expect(courtState.changeDelay.toNumber()).to.equal(changeDelay);
+
+        let changeState = await cs.program.account.pendingCourtChange.fetchNullable(cs.getCourtChange());
+        expect(changeState).to.be.null;
+    });
+
+    it('apply_before_effective_fails!', async () => {
+        await cs.editCourt(maxDisputeVotes, 0, 1000, changeDelay);
+        await expectFailure(() => cs.applyCourtChange(), "CourtChangeNotEffective");
+
+        let courtState = await cs.program.account.court.fetch(cs.court.publicKey);
+        expect(courtState.crankTip.toNumber()).to.equal(0);
+    });
+
+    it('only_edit_authority_cancels!', async () => {
+        await expectFailure(() => cs.cancelCourtChange(stranger), "InvalidEditAuthority");
+    });
+
+    it('cancel_court_change!', async () => {
+        await cs.cancelCourtChange();
+
+        let changeState = await cs.program.account.pendingCourtChange.fetchNullable(cs.getCourtChange());
+        expect(changeState).to.be.null;
+    });
+
+    it('juror_empties_stake_vault!', async () => {
+        let ata = await juror.getOrCreateRepATA(juror.user.publicKey, false);
+        await cs.mintRepTokens(ata.address, stake.toNumber());
+        await juror.depositStake(stake);
+        await juror.requestUnstake(stake);
+        await juror.withdrawStake();
+
+        let balance = await cs.getTokenBalance(juror.getStakeVault());
+        expect(balance).to.equal(0);
+    });
+
+    it('rep_mint_swap_closes_empty_stake_vault!', async () => {
+        newRepMint = await createMint(cs.connection, cs.editAuthority, cs.mintAuthority.publicKey, null, decimals);
+
+        await cs.editCourt(maxDisputeVotes, 0, 0, changeDelay, newRepMint);
+        let changeState = await cs.program.account.pendingCourtChange.fetch(cs.getCourtChange());
+        await waitUntil(changeState.effectiveAt);
+        await cs.applyCourtChange();
+
+        let courtState = await cs.program.account.court.fetch(cs.court.publicKey);
+        expect(courtState.repMint.toBase58()).to.equal(newRepMint.toBase58());
+
+        // deposit_stake recreates it under the new mint
+        let vaultInfo = await cs.connection.getAccountInfo(juror.getStakeVault());
+        expect(vaultInfo).to.be.null;
+    });
+});
@@ -0,0 +1,80 @@
+import { Keypair } from '@solana/web3.js';
+import { expect } from 'chai';
+import { maxDisputeVotes, decimals, courtName } from './config';
+import { CourtSuite } from './court-suite';
+
+describe('agora-court-transfer', () => {
+    //find the provider and set the anchor provider
+    let cs = new CourtSuite();
+    let newAuthority = Keypair.generate();
+    let newProtocol = Keypair.generate();
+
+    const expectFailure = async (call: () => Promise<void>, code: string) => {
+        let failed = false;
+        try {
+            await call();
+        } catch (err) {
+            failed = true;
+            expect(err.error.errorCode.code).to.equal(code);
+        }
+        expect(failed).to.be.true;
+    }
+
+    it('setup_court!', async () => {
+        await cs.setAccounts(decimals, courtName);
+        await cs.initCourt(maxDisputeVotes);
+    });
+
+    it('only_edit_authority_proposes!', async () => {
+        await expectFailure(() => cs.proposeTransfer(newAuthority.publicKey, null, newAuthority), "InvalidEditAuthority");
+    });
+
+    it('propose_transfer!', async () => {
+        await cs.proposeTransfer(newAuthority.publicKey, newProtocol.publicKey);
+
+        let courtState = await cs.program.account.court.fetch(cs.court.publicKey);
+        expect(courtState.pendingAuthority.toBase58()).to.equal(newAuthority.publicKey.toBase58());
+        expect(courtState.pendingProtocol.toBase58()).to.equal(newProtocol.publicKey.toBase58());
+        // nothing changes until the new keys accept
+        expect(courtState.editAuthority.toBase58()).to.equal(cs.editAuthority.publicKey.toBase58());
+    });
+
+    it('wrong_key_cannot_accept!', async () => {
+        await expectFailure(() => cs.acceptAuthority(newProtocol), "NoPendingTransfer");
+        await expectFailure(() => cs.acceptProtocol(newAuthority), "NoPendingTransfer");
+    });
+
+    it('cancel_transfer!', async () => {
+        await cs.cancelTransfer();
+
+        let courtState = await cs.program.account.court.fetch(cs.court.publicKey);
+        expect(courtState.pendingAuthority).to.be.null;
+        expect(courtState.pendingProtocol).to.be.null;
+
+        await expectFailure(() => cs.acceptAuthority(newAuthority), "NoPendingTransfer");
+        await expectFailure(() => cs.cancelTransfer(), "NoPendingTransfer");
+    });
+
+    it('accept_protocol!', async () => {
+        await cs.proposeTransfer(newAuthority.publicKey, newProtocol.publicKey);
+        await cs.acceptProtocol(newProtocol);
+
+        let courtState = await cs.program.account.court.fetch(cs.court.publicKey);
+        expect(courtState.protocol.toBase58()).to.equal(newProtocol.publicKey.toBase58());
+        expect(courtState.pendingProtocol).to.be.null;
+        // the authority proposal is untouched
+        expect(courtState.pendingAuthority.toBase58()).to.equal(newAuthority.publicKey.toBase58());
+    });
+
+    it('accept_authority!', async () => {
+        let oldAuthority = cs.editAuthority;
+        await cs.acceptAuthority(newAuthority);
+
+        let courtState = await cs.program.account.court.fetch(cs.court.publicKey);
+        expect(courtState.editAuthority.toBase58()).to.equal(newAuthority.publicKey.toBase58());
+        expect(courtState.pendingAuthority).to.be.null;
+
+        // the old authority is locked out
+        await expectFailure(() => cs.proposeTransfer(oldAuthority.publicKey, null, oldAuthority), "InvalidEditAuthority");
+    });
+});
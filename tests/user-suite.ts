@@ -113,6 +113,18 @@ export class UserSuite {
         return account;
     }
 
+    getRepVault = (dispute: PublicKey) => {
+        return this.findProgramAddress("rep_vault", [dispute]).publicKey;
+    }
+
+    getJurorPass = () => {
+        return this.findProgramAddress("juror", [this.courtConfig.court, this.user.publicKey]).publicKey;
+    }
+
+    getStakeVault = () => {
+        return this.findProgramAddress("stake_vault", [this.courtConfig.court]).publicKey;
+    }
+
     requestAirdrop = async (pubkey: PublicKey, sol: number) => {
         let signature = await this.connection.requestAirdrop(pubkey, sol*LAMPORTS_PER_SOL);
         await this.confirmTx(signature);
@@ -162,10 +174,101 @@ export class UserSuite {
         }
     }
 
+    closeRecord = async () => {
+        try {
+            await this.program.methods
+                .closeRecord(
+                    this.courtConfig.courtName
+                )
+                .accounts({
+                    record: this.record.publicKey,
+                    court: this.courtConfig.court,
+                    payer: this.user.publicKey
+                })
+                .signers([this.user])
+                .rpc()
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    depositStake = async (amount: anchor.BN) => { // ensure to mint to user ATA first
+        const userRepATA = await this.getOrCreateRepATA(this.user.publicKey, false);
+
+        try {
+            await this.program.methods
+                .depositStake(
+                    this.courtConfig.courtName,
+                    amount
+                )
+                .accounts({
+                    record: this.record.publicKey,
+                    stakeVault: this.getStakeVault(),
+                    court: this.courtConfig.court,
+                    user: this.user.publicKey,
+                    userRepAta: userRepATA.address,
+                    repMint: this.courtConfig.repMint,
+                    systemProgram: SystemProgram.programId,
+                    tokenProgram: TOKEN_PROGRAM_ID
+                })
+                .signers([this.user])
+                .rpc()
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    requestUnstake = async (amount: anchor.BN) => {
+        try {
+            await this.program.methods
+                .requestUnstake(
+                    this.courtConfig.courtName,
+                    amount
+                )
+                .accounts({
+                    record: this.record.publicKey,
+                    court: this.courtConfig.court,
+                    user: this.user.publicKey,
+                })
+                .signers([this.user])
+                .rpc()
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    withdrawStake = async () => {
+        const userRepATA = await this.getOrCreateRepATA(this.user.publicKey, false);
+
+        try {
+            await this.program.methods
+                .withdrawStake(
+                    this.courtConfig.courtName
+                )
+                .accounts({
+                    record: this.record.publicKey,
+                    stakeVault: this.getStakeVault(),
+                    court: this.courtConfig.court,
+                    user: this.user.publicKey,
+                    userRepAta: userRepATA.address,
+                    repMint: this.courtConfig.repMint,
+                    tokenProgram: TOKEN_PROGRAM_ID
+                })
+                .signers([this.user])
+                .rpc()
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
     interact = async (disputeID: anchor.BN) => { // ensure to mint to user ATA if rep cost
         const disputePDA = this.findProgramAddress("dispute", [this.courtConfig.court, disputeID]).publicKey;
         const userRepATA = await this.getOrCreateRepATA(this.user.publicKey);
-        const repVaultATA = this.getRepATA(disputePDA);
+        const repVaultATA = this.getRepVault(disputePDA);
 
         try {
             await this.program.methods
@@ -185,8 +288,8 @@ export class UserSuite {
                     repMint: this.courtConfig.repMint,
                     payMint: this.program.programId, //None
                     systemProgram: SystemProgram.programId,
-                    tokenProgram: TOKEN_PROGRAM_ID,
-                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID
+                    repTokenProgram: TOKEN_PROGRAM_ID,
+                    payTokenProgram: TOKEN_PROGRAM_ID
                 })
                 .signers(
                     [this.user]
@@ -207,7 +310,7 @@ export class UserSuite {
                 .initializeCase(
                     this.courtConfig.courtName,
                     disputeID,
-                    evidence
+                    { uri: evidence, contentHash: new Array(32).fill(0), mimeType: "text/plain" }
                 )
                 .accounts({
                     case: casePDA.publicKey,
@@ -229,10 +332,10 @@ export class UserSuite {
         this.case = casePDA;
     }
 
-    selectVote = async (disputeID: anchor.BN, candidate: PublicKey) => {
+    // gating accounts are only needed on allow-listed / collection-gated courts
+    selectVote = async (disputeID: anchor.BN, candidate: PublicKey, gating: { jurorPass?: PublicKey, nftAta?: PublicKey, nftMetadata?: PublicKey } = {}) => {
         // pdas and atas
         const disputePDA = this.findProgramAddress("dispute", [this.courtConfig.court, disputeID]).publicKey;
-        const repVaultATA = this.getRepATA(disputePDA);
         const userRepATA = await this.getOrCreateRepATA(this.user.publicKey, false);
         
         // create salt
@@ -259,14 +362,16 @@ export class UserSuite {
                 .accounts({
                     voterRecord: this.record.publicKey,
                     dispute: disputePDA,
-                    repVault: repVaultATA,
                     court: this.courtConfig.court,
                     repMint: this.courtConfig.repMint,
                     payer: this.user.publicKey,
                     userRepAta: userRepATA.address,
-                    systemProgram: SystemProgram.programId,
-                    tokenProgram: TOKEN_PROGRAM_ID,
-                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID
+                    childCourt: this.program.programId, //NULL
+                    childRecord: this.program.programId, //NULL
+                    jurorPass: gating.jurorPass ?? this.program.programId, //NULL
+                    nftAta: gating.nftAta ?? this.program.programId, //NULL
+                    nftMetadata: gating.nftMetadata ?? this.program.programId, //NULL
+                    systemProgram: SystemProgram.programId
                 })
                 .signers(
                     [this.user]
@@ -281,6 +386,67 @@ export class UserSuite {
         return hashArr;
     }
 
+    getAmicusSubmission = (disputeID: anchor.BN, submitter: PublicKey = this.user.publicKey) => {
+        const disputePDA = this.findProgramAddress("dispute", [this.courtConfig.court, disputeID]).publicKey;
+        return this.findProgramAddress("amicus", [disputePDA, submitter]).publicKey;
+    }
+
+    getAmicusFlag = (submission: PublicKey) => {
+        return this.findProgramAddress("amicus_flag", [submission, this.user.publicKey]).publicKey;
+    }
+
+    submitAmicusEvidence = async (disputeID: anchor.BN, evidence: string) => {
+        const disputePDA = this.findProgramAddress("dispute", [this.courtConfig.court, disputeID]).publicKey;
+
+        try {
+            await this.program.methods
+                .submitAmicusEvidence(
+                    this.courtConfig.courtName,
+                    disputeID,
+                    { uri: evidence, contentHash: new Array(32).fill(0), mimeType: "text/plain" }
+                )
+                .accounts({
+                    submission: this.getAmicusSubmission(disputeID),
+                    dispute: disputePDA,
+                    court: this.courtConfig.court,
+                    submitter: this.user.publicKey,
+                    systemProgram: SystemProgram.programId
+                })
+                .signers([this.user])
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    flagAmicusEvidence = async (disputeID: anchor.BN, submitter: PublicKey) => {
+        const disputePDA = this.findProgramAddress("dispute", [this.courtConfig.court, disputeID]).publicKey;
+        const submission = this.getAmicusSubmission(disputeID, submitter);
+
+        try {
+            await this.program.methods
+                .flagAmicusEvidence(
+                    this.courtConfig.courtName,
+                    disputeID
+                )
+                .accounts({
+                    flag: this.getAmicusFlag(submission),
+                    submission: submission,
+                    voterRecord: this.record.publicKey,
+                    dispute: disputePDA,
+                    court: this.courtConfig.court,
+                    juror: this.user.publicKey,
+                    systemProgram: SystemProgram.programId
+                })
+                .signers([this.user])
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
     revealVote = async (disputeID: anchor.BN) => {
         const disputePDA = this.findProgramAddress("dispute", [this.courtConfig.court, disputeID]).publicKey;
         const casePDA = this.findProgramAddress("case", [disputePDA, this.votedFor]).publicKey;
@@ -310,7 +476,7 @@ export class UserSuite {
 
     claim = async (disputeID: anchor.BN) => {
         const disputePDA = this.findProgramAddress("dispute", [this.courtConfig.court, disputeID]).publicKey;
-        const repVaultATA = this.getRepATA(disputePDA);
+        const repVaultATA = this.getRepVault(disputePDA);
         const userRepATA = this.getRepATA(this.user.publicKey, false);
 
         try {
@@ -330,10 +496,52 @@ export class UserSuite {
                     userRepAta: userRepATA,
                     repMint: this.courtConfig.repMint,
                     payMint: this.program.programId, //None
-                    tokenProgram: TOKEN_PROGRAM_ID,
-                    systemProgram: SystemProgram.programId,
-                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID
+                    repTokenProgram: TOKEN_PROGRAM_ID,
+                    payTokenProgram: TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId
+                })
+                .signers(
+                    [this.user]
+                )
+                .rpc();
+        } catch (err) {
+            console.log(err);
+            throw err;
+        }
+    }
+
+    claimMany = async (disputeIDs: anchor.BN[]) => {
+        const userRepATA = this.getRepATA(this.user.publicKey, false);
+
+        // [dispute, rep vault, pay vault] per dispute
+        const remainingAccounts = disputeIDs.flatMap((disputeID) => {
+            const disputePDA = this.findProgramAddress("dispute", [this.courtConfig.court, disputeID]).publicKey;
+            return [
+                { pubkey: disputePDA, isWritable: true, isSigner: false },
+                { pubkey: this.getRepVault(disputePDA), isWritable: true, isSigner: false },
+                { pubkey: this.program.programId, isWritable: false, isSigner: false }, //None
+            ];
+        });
+
+        try {
+            await this.program.methods
+                .claimMany(
+                    this.courtConfig.courtName,
+                    disputeIDs
+                )
+                .accounts({
+                    voterRecord: this.record.publicKey,
+                    court: this.courtConfig.court,
+                    user: this.user.publicKey,
+                    userPayAta: this.program.programId, //None
+                    userRepAta: userRepATA,
+                    repMint: this.courtConfig.repMint,
+                    payMint: this.program.programId, //None
+                    repTokenProgram: TOKEN_PROGRAM_ID,
+                    payTokenProgram: TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId
                 })
+                .remainingAccounts(remainingAccounts)
                 .signers(
                     [this.user]
                 )
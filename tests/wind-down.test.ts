@@ -0,0 +1,115 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { maxDisputeVotes, decimals, courtName, noRevealDisputeOptions as disputeOptions } from './config';
+import { CourtSuite } from './court-suite';
+import { UserSuite } from './user-suite';
+
+describe('agora-court-wind-down', () => {
+    //find the provider and set the anchor provider
+    let cs = new CourtSuite();
+    let partyOne = new UserSuite();
+    let partyTwo = new UserSuite();
+    let voter = new UserSuite();
+    let lateVoter = new UserSuite();
+
+    it('setup_court_and_dispute!', async () => {
+        await cs.setAccounts(decimals, courtName);
+        await cs.initCourt(maxDisputeVotes);
+        await cs.initDispute(disputeOptions);
+
+        let courtConfig = cs.getCourtConfig();
+        for (const user of [partyOne, partyTwo, voter, lateVoter]) {
+            await user.setAccounts(courtConfig);
+            await user.initRecord();
+        }
+
+        // the protocol subsidy sits in the dispute vault
+        let balance = await cs.getTokenBalance(cs.getRepATA(cs.protocol.publicKey));
+        expect(balance).to.equal(0);
+    });
+
+    it('parties_submit_cases_and_voter_commits!', async () => {
+        for (const party of [partyOne, partyTwo]) {
+            let ata = await party.getOrCreateRepATA(party.user.publicKey, false);
+            await cs.mintRepTokens(ata.address, disputeOptions.partyRepCost.toNumber());
+            await party.interact(cs.disputeID);
+        }
+
+        await partyOne.initCase(cs.disputeID, "I'm right guys trust");
+        await partyTwo.initCase(cs.disputeID, "Don't trust that bozo");
+
+        for (const user of [voter, lateVoter]) {
+            let ata = await user.getOrCreateRepATA(user.user.publicKey, false);
+            await cs.mintRepTokens(ata.address, disputeOptions.voterRepRequired.toNumber());
+            await user.depositStake(disputeOptions.voterRepRequired);
+        }
+        await voter.selectVote(cs.disputeID, partyTwo.user.publicKey);
+    });
+
+    it('paused_court_rejects_votes!', async () => {
+        await cs.setPaused(true);
+
+        let failed = false;
+        try {
+            await lateVoter.selectVote(cs.disputeID, partyOne.user.publicKey);
+        } catch (err) {
+            failed = true;
+            expect(err.error.errorCode.code).to.equal("CourtNotActive");
+        }
+        expect(failed).to.be.true;
+    });
+
+    it('wind_down_dispute_needs_winding_down_court!', async () => {
+        let failed = false;
+        try {
+            await cs.windDownDispute();
+        } catch (err) {
+            failed = true;
+            expect(err.error.errorCode.code).to.equal("CourtNotWindingDown");
+        }
+        expect(failed).to.be.true;
+    });
+
+    it('wind_down_concludes_without_winner!', async () => {
+        await cs.windDown();
+        await cs.windDownDispute();
+
+        let disputeState = await cs.program.account.dispute.fetch(cs.dispute.publicKey);
+        expect(JSON.stringify(disputeState.status)).to.equal(JSON.stringify({ concluded: { winner: null } }));
+    });
+
+    it('parties_and_voter_are_refunded!', async () => {
+        for (const party of [partyOne, partyTwo]) {
+            await party.claim(cs.disputeID);
+
+            let balance = await cs.getTokenBalance(cs.getRepATA(party.user.publicKey));
+            expect(balance).to.equal(disputeOptions.partyRepCost.toNumber());
+        }
+
+        await voter.claim(cs.disputeID);
+
+        let recordState = await cs.program.account.voterRecord.fetch(voter.record.publicKey);
+        expect(recordState.claimQueue).to.be.empty;
+        expect(recordState.jurorStake.locked.eqn(0)).to.be.true;
+        expect(recordState.jurorStake.free.eq(disputeOptions.voterRepRequired)).to.be.true;
+    });
+
+    it('protocol_is_refunded_once!', async () => {
+        await cs.refundProtocol();
+
+        let balance = await cs.getTokenBalance(cs.getRepATA(cs.protocol.publicKey));
+        expect(balance).to.equal(disputeOptions.protocolRep.toNumber());
+
+        let vaultBalance = await cs.getTokenBalance(cs.getRepVault(cs.dispute.publicKey));
+        expect(vaultBalance).to.equal(0);
+
+        let failed = false;
+        try {
+            await cs.refundProtocol();
+        } catch (err) {
+            failed = true;
+            expect(err.error.errorCode.code).to.equal("ProtocolRefundUnavailable");
+        }
+        expect(failed).to.be.true;
+    });
+});